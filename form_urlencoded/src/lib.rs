@@ -29,12 +29,34 @@ use std::str;
 /// converted to `[("#first", "%try%")]`.
 #[inline]
 pub fn parse(input: &[u8]) -> Parse<'_> {
-    Parse { input }
+    parse_with_separators(input, b"&")
 }
-/// The return type of `parse()`.
+
+/// Like `parse()`, but pairs are separated by any of the bytes in `separators`
+/// instead of only `&`.
+///
+/// This is useful for legacy servers that use `;` as a separator.
+///
+/// ```rust
+/// let pairs: Vec<_> = form_urlencoded::parse_with_separators(b"a=1;b=2&c", b"&;")
+///     .into_owned()
+///     .collect();
+/// assert_eq!(pairs, vec![
+///     ("a".to_owned(), "1".to_owned()),
+///     ("b".to_owned(), "2".to_owned()),
+///     ("c".to_owned(), "".to_owned()),
+/// ]);
+/// ```
+#[inline]
+pub fn parse_with_separators<'a>(input: &'a [u8], separators: &'a [u8]) -> Parse<'a> {
+    Parse { input, separators }
+}
+
+/// The return type of `parse()` and `parse_with_separators()`.
 #[derive(Copy, Clone)]
 pub struct Parse<'a> {
     input: &'a [u8],
+    separators: &'a [u8],
 }
 
 impl<'a> Iterator for Parse<'a> {
@@ -45,7 +67,8 @@ impl<'a> Iterator for Parse<'a> {
             if self.input.is_empty() {
                 return None;
             }
-            let mut split2 = self.input.splitn(2, |&b| b == b'&');
+            let separators = self.separators;
+            let mut split2 = self.input.splitn(2, |b| separators.contains(b));
             let sequence = split2.next().unwrap();
             self.input = split2.next().unwrap_or(&[][..]);
             if sequence.is_empty() {
@@ -255,6 +278,38 @@ impl<'a, T: Target> Serializer<'a, T> {
         self
     }
 
+    /// Append a name/value pair that is already `application/x-www-form-urlencoded`,
+    /// without encoding it again.
+    ///
+    /// Both `name` and `value` may only contain ASCII alphanumerics, `*`, `-`, `.`, `_`, `~`,
+    /// `+` for spaces, and `%` followed by two hexadecimal digits.
+    /// The serializer's encoding override is not applied.
+    /// Return an error and leave the serialization unchanged for anything else,
+    /// like a `&` or `=` that would change the meaning of the serialization.
+    ///
+    /// ```rust
+    /// let mut serializer = form_urlencoded::Serializer::new(String::new());
+    /// serializer.append_pair("q", "a&b");
+    /// serializer.append_pair_encoded("sig", "x%2By+z").unwrap();
+    /// assert!(serializer.append_pair_encoded("a", "b&c=d").is_err());
+    /// assert!(serializer.append_pair_encoded("a", "100%").is_err());
+    /// assert_eq!(serializer.finish(), "q=a%26b&sig=x%2By+z");
+    /// ```
+    ///
+    /// Panics if called after `.finish()`.
+    #[allow(clippy::result_unit_err)]
+    pub fn append_pair_encoded(&mut self, name: &str, value: &str) -> Result<&mut Self, ()> {
+        if !is_form_urlencoded(name) || !is_form_urlencoded(value) {
+            return Err(());
+        }
+        let string = string(&mut self.target);
+        append_separator_if_needed(string, self.start_position);
+        string.push_str(name);
+        string.push('=');
+        string.push_str(value);
+        Ok(self)
+    }
+
     /// Serialize and append a name of parameter without any value.
     ///
     /// Panics if called after `.finish()`.
@@ -377,6 +432,25 @@ fn append_key_only(
     append_encoded(name, string, encoding);
 }
 
+fn is_form_urlencoded(s: &str) -> bool {
+    let mut bytes = s.bytes();
+    while let Some(byte) = bytes.next() {
+        let valid = match byte {
+            b'~' | b'+' => true,
+            _ if byte_serialized_unchanged(byte) => true,
+            b'%' => match (bytes.next(), bytes.next()) {
+                (Some(a), Some(b)) => a.is_ascii_hexdigit() && b.is_ascii_hexdigit(),
+                _ => false,
+            },
+            _ => false,
+        };
+        if !valid {
+            return false;
+        }
+    }
+    true
+}
+
 fn append_encoded(s: &str, string: &mut String, encoding: EncodingOverride<'_>) {
     string.extend(byte_serialize(&encode(encoding, s)))
 }
@@ -0,0 +1,18 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use std::char;
+
+// Encode arbitrary code points, which needn't come from valid UTF-8, and decode them back.
+fuzz_target!(|data: &[u8]| {
+    let input: Vec<char> = data
+        .chunks(3)
+        .filter_map(|bytes| {
+            let value = bytes.iter().fold(0, |value, &b| value << 8 | u32::from(b));
+            char::from_u32(value % 0x11_0000)
+        })
+        .collect();
+    if let Some(encoded) = idna::punycode::encode(&input) {
+        assert!(encoded.is_ascii());
+        assert_eq!(idna::punycode::decode(&encoded), Some(input));
+    }
+});
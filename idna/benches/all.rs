@@ -3,7 +3,7 @@ extern crate bencher;
 extern crate idna;
 
 use bencher::{black_box, Bencher};
-use idna::Config;
+use idna::{Config, Idna};
 
 fn to_unicode_puny_label(bench: &mut Bencher) {
     let encoded = "abc.xn--mgbcm";
@@ -35,12 +35,67 @@ fn to_ascii_simple(bench: &mut Bencher) {
     bench.iter(|| config.to_ascii(black_box(encoded)));
 }
 
+fn to_ascii_mixed_case(bench: &mut Bencher) {
+    let encoded = "WWW.Example.COM";
+    let config = Config::default();
+    bench.iter(|| config.to_ascii(black_box(encoded)));
+}
+
 fn to_ascii_merged(bench: &mut Bencher) {
     let encoded = "beispiel.vermögensberater";
     let config = Config::default();
     bench.iter(|| config.to_ascii(black_box(encoded)));
 }
 
+fn to_ascii_merged_assume_nfc(bench: &mut Bencher) {
+    let encoded = "beispiel.vermögensberater";
+    let config = Config::default().assume_nfc(true);
+    bench.iter(|| config.to_ascii(black_box(encoded)));
+}
+
+fn to_ascii_merged_reused(bench: &mut Bencher) {
+    let encoded = "beispiel.vermögensberater";
+    let mut codec = Idna::new(Config::default());
+    let mut out = String::new();
+    bench.iter(|| {
+        out.clear();
+        codec.to_ascii(black_box(encoded), &mut out)
+    });
+}
+
+fn to_unicode_merged_label_reused(bench: &mut Bencher) {
+    let encoded = "Beispiel.xn--vermgensberater-ctb";
+    let mut codec = Idna::new(Config::default());
+    let mut out = String::new();
+    bench.iter(|| {
+        out.clear();
+        codec.to_unicode(black_box(encoded), &mut out)
+    });
+}
+
+fn punycode_decode_16(bench: &mut Bencher) {
+    let encoded = idna::punycode::encode_str(&cjk_label(16)).unwrap();
+    bench.iter(|| idna::punycode::decode(black_box(&encoded)));
+}
+
+fn punycode_decode_63(bench: &mut Bencher) {
+    let encoded = idna::punycode::encode_str(&cjk_label(63)).unwrap();
+    bench.iter(|| idna::punycode::decode(black_box(&encoded)));
+}
+
+fn punycode_decode_1000(bench: &mut Bencher) {
+    let encoded = idna::punycode::encode_str(&cjk_label(1000)).unwrap();
+    bench.iter(|| idna::punycode::decode(black_box(&encoded)));
+}
+
+/// Code points in descending order, so that each one is inserted before the previous ones.
+fn cjk_label(len: u32) -> String {
+    (0..len)
+        .rev()
+        .map(|i| std::char::from_u32(0x4E00 + 7 * i).unwrap())
+        .collect()
+}
+
 benchmark_group!(
     benches,
     to_unicode_puny_label,
@@ -48,6 +103,13 @@ benchmark_group!(
     to_unicode_merged_label,
     to_ascii_puny_label,
     to_ascii_simple,
+    to_ascii_mixed_case,
     to_ascii_merged,
+    to_ascii_merged_assume_nfc,
+    to_ascii_merged_reused,
+    to_unicode_merged_label_reused,
+    punycode_decode_16,
+    punycode_decode_63,
+    punycode_decode_1000,
 );
 benchmark_main!(benches);
@@ -0,0 +1,536 @@
+// Copyright 2013-2020 The rust-url developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Generated by make_joining_type_table.py
+
+static JOINING_TYPE_TABLE: &[(char, char, JoiningType)] = &[
+    ('\u{ad}', '\u{ad}', JoiningType::Transparent),
+    ('\u{300}', '\u{36f}', JoiningType::Transparent),
+    ('\u{483}', '\u{489}', JoiningType::Transparent),
+    ('\u{591}', '\u{5bd}', JoiningType::Transparent),
+    ('\u{5bf}', '\u{5bf}', JoiningType::Transparent),
+    ('\u{5c1}', '\u{5c2}', JoiningType::Transparent),
+    ('\u{5c4}', '\u{5c5}', JoiningType::Transparent),
+    ('\u{5c7}', '\u{5c7}', JoiningType::Transparent),
+    ('\u{610}', '\u{61a}', JoiningType::Transparent),
+    ('\u{61c}', '\u{61c}', JoiningType::Transparent),
+    ('\u{620}', '\u{620}', JoiningType::Dual),
+    ('\u{622}', '\u{625}', JoiningType::Right),
+    ('\u{626}', '\u{626}', JoiningType::Dual),
+    ('\u{627}', '\u{627}', JoiningType::Right),
+    ('\u{628}', '\u{628}', JoiningType::Dual),
+    ('\u{629}', '\u{629}', JoiningType::Right),
+    ('\u{62a}', '\u{62e}', JoiningType::Dual),
+    ('\u{62f}', '\u{632}', JoiningType::Right),
+    ('\u{633}', '\u{63f}', JoiningType::Dual),
+    ('\u{641}', '\u{647}', JoiningType::Dual),
+    ('\u{648}', '\u{648}', JoiningType::Right),
+    ('\u{649}', '\u{64a}', JoiningType::Dual),
+    ('\u{64b}', '\u{65f}', JoiningType::Transparent),
+    ('\u{66e}', '\u{66f}', JoiningType::Dual),
+    ('\u{670}', '\u{670}', JoiningType::Transparent),
+    ('\u{671}', '\u{673}', JoiningType::Right),
+    ('\u{675}', '\u{677}', JoiningType::Right),
+    ('\u{678}', '\u{687}', JoiningType::Dual),
+    ('\u{688}', '\u{699}', JoiningType::Right),
+    ('\u{69a}', '\u{6bf}', JoiningType::Dual),
+    ('\u{6c0}', '\u{6c0}', JoiningType::Right),
+    ('\u{6c1}', '\u{6c2}', JoiningType::Dual),
+    ('\u{6c3}', '\u{6cb}', JoiningType::Right),
+    ('\u{6cc}', '\u{6cc}', JoiningType::Dual),
+    ('\u{6cd}', '\u{6cd}', JoiningType::Right),
+    ('\u{6ce}', '\u{6ce}', JoiningType::Dual),
+    ('\u{6cf}', '\u{6cf}', JoiningType::Right),
+    ('\u{6d0}', '\u{6d1}', JoiningType::Dual),
+    ('\u{6d2}', '\u{6d3}', JoiningType::Right),
+    ('\u{6d5}', '\u{6d5}', JoiningType::Right),
+    ('\u{6d6}', '\u{6dc}', JoiningType::Transparent),
+    ('\u{6df}', '\u{6e4}', JoiningType::Transparent),
+    ('\u{6e7}', '\u{6e8}', JoiningType::Transparent),
+    ('\u{6ea}', '\u{6ed}', JoiningType::Transparent),
+    ('\u{6ee}', '\u{6ef}', JoiningType::Right),
+    ('\u{6fa}', '\u{6fc}', JoiningType::Dual),
+    ('\u{6ff}', '\u{6ff}', JoiningType::Dual),
+    ('\u{70f}', '\u{70f}', JoiningType::Transparent),
+    ('\u{710}', '\u{710}', JoiningType::Right),
+    ('\u{711}', '\u{711}', JoiningType::Transparent),
+    ('\u{712}', '\u{714}', JoiningType::Dual),
+    ('\u{715}', '\u{719}', JoiningType::Right),
+    ('\u{71a}', '\u{71d}', JoiningType::Dual),
+    ('\u{71e}', '\u{71e}', JoiningType::Right),
+    ('\u{71f}', '\u{727}', JoiningType::Dual),
+    ('\u{728}', '\u{728}', JoiningType::Right),
+    ('\u{729}', '\u{729}', JoiningType::Dual),
+    ('\u{72a}', '\u{72a}', JoiningType::Right),
+    ('\u{72b}', '\u{72b}', JoiningType::Dual),
+    ('\u{72c}', '\u{72c}', JoiningType::Right),
+    ('\u{72d}', '\u{72e}', JoiningType::Dual),
+    ('\u{72f}', '\u{72f}', JoiningType::Right),
+    ('\u{730}', '\u{74a}', JoiningType::Transparent),
+    ('\u{74d}', '\u{74d}', JoiningType::Right),
+    ('\u{74e}', '\u{758}', JoiningType::Dual),
+    ('\u{759}', '\u{75b}', JoiningType::Right),
+    ('\u{75c}', '\u{76a}', JoiningType::Dual),
+    ('\u{76b}', '\u{76c}', JoiningType::Right),
+    ('\u{76d}', '\u{770}', JoiningType::Dual),
+    ('\u{771}', '\u{771}', JoiningType::Right),
+    ('\u{772}', '\u{772}', JoiningType::Dual),
+    ('\u{773}', '\u{774}', JoiningType::Right),
+    ('\u{775}', '\u{777}', JoiningType::Dual),
+    ('\u{778}', '\u{779}', JoiningType::Right),
+    ('\u{77a}', '\u{77f}', JoiningType::Dual),
+    ('\u{7a6}', '\u{7b0}', JoiningType::Transparent),
+    ('\u{7ca}', '\u{7ea}', JoiningType::Dual),
+    ('\u{7eb}', '\u{7f3}', JoiningType::Transparent),
+    ('\u{7fd}', '\u{7fd}', JoiningType::Transparent),
+    ('\u{816}', '\u{819}', JoiningType::Transparent),
+    ('\u{81b}', '\u{823}', JoiningType::Transparent),
+    ('\u{825}', '\u{827}', JoiningType::Transparent),
+    ('\u{829}', '\u{82d}', JoiningType::Transparent),
+    ('\u{840}', '\u{840}', JoiningType::Right),
+    ('\u{841}', '\u{845}', JoiningType::Dual),
+    ('\u{846}', '\u{847}', JoiningType::Right),
+    ('\u{848}', '\u{848}', JoiningType::Dual),
+    ('\u{849}', '\u{849}', JoiningType::Right),
+    ('\u{84a}', '\u{853}', JoiningType::Dual),
+    ('\u{854}', '\u{854}', JoiningType::Right),
+    ('\u{855}', '\u{855}', JoiningType::Dual),
+    ('\u{856}', '\u{858}', JoiningType::Right),
+    ('\u{859}', '\u{85b}', JoiningType::Transparent),
+    ('\u{860}', '\u{860}', JoiningType::Dual),
+    ('\u{862}', '\u{865}', JoiningType::Dual),
+    ('\u{867}', '\u{867}', JoiningType::Right),
+    ('\u{868}', '\u{868}', JoiningType::Dual),
+    ('\u{869}', '\u{86a}', JoiningType::Right),
+    ('\u{870}', '\u{882}', JoiningType::Right),
+    ('\u{886}', '\u{886}', JoiningType::Dual),
+    ('\u{889}', '\u{88d}', JoiningType::Dual),
+    ('\u{88e}', '\u{88e}', JoiningType::Right),
+    ('\u{88f}', '\u{88f}', JoiningType::Dual),
+    ('\u{897}', '\u{89f}', JoiningType::Transparent),
+    ('\u{8a0}', '\u{8a9}', JoiningType::Dual),
+    ('\u{8aa}', '\u{8ac}', JoiningType::Right),
+    ('\u{8ae}', '\u{8ae}', JoiningType::Right),
+    ('\u{8af}', '\u{8b0}', JoiningType::Dual),
+    ('\u{8b1}', '\u{8b2}', JoiningType::Right),
+    ('\u{8b3}', '\u{8b8}', JoiningType::Dual),
+    ('\u{8b9}', '\u{8b9}', JoiningType::Right),
+    ('\u{8ba}', '\u{8c8}', JoiningType::Dual),
+    ('\u{8ca}', '\u{8e1}', JoiningType::Transparent),
+    ('\u{8e3}', '\u{902}', JoiningType::Transparent),
+    ('\u{93a}', '\u{93a}', JoiningType::Transparent),
+    ('\u{93c}', '\u{93c}', JoiningType::Transparent),
+    ('\u{941}', '\u{948}', JoiningType::Transparent),
+    ('\u{94d}', '\u{94d}', JoiningType::Transparent),
+    ('\u{951}', '\u{957}', JoiningType::Transparent),
+    ('\u{962}', '\u{963}', JoiningType::Transparent),
+    ('\u{981}', '\u{981}', JoiningType::Transparent),
+    ('\u{9bc}', '\u{9bc}', JoiningType::Transparent),
+    ('\u{9c1}', '\u{9c4}', JoiningType::Transparent),
+    ('\u{9cd}', '\u{9cd}', JoiningType::Transparent),
+    ('\u{9e2}', '\u{9e3}', JoiningType::Transparent),
+    ('\u{9fe}', '\u{9fe}', JoiningType::Transparent),
+    ('\u{a01}', '\u{a02}', JoiningType::Transparent),
+    ('\u{a3c}', '\u{a3c}', JoiningType::Transparent),
+    ('\u{a41}', '\u{a42}', JoiningType::Transparent),
+    ('\u{a47}', '\u{a48}', JoiningType::Transparent),
+    ('\u{a4b}', '\u{a4d}', JoiningType::Transparent),
+    ('\u{a51}', '\u{a51}', JoiningType::Transparent),
+    ('\u{a70}', '\u{a71}', JoiningType::Transparent),
+    ('\u{a75}', '\u{a75}', JoiningType::Transparent),
+    ('\u{a81}', '\u{a82}', JoiningType::Transparent),
+    ('\u{abc}', '\u{abc}', JoiningType::Transparent),
+    ('\u{ac1}', '\u{ac5}', JoiningType::Transparent),
+    ('\u{ac7}', '\u{ac8}', JoiningType::Transparent),
+    ('\u{acd}', '\u{acd}', JoiningType::Transparent),
+    ('\u{ae2}', '\u{ae3}', JoiningType::Transparent),
+    ('\u{afa}', '\u{aff}', JoiningType::Transparent),
+    ('\u{b01}', '\u{b01}', JoiningType::Transparent),
+    ('\u{b3c}', '\u{b3c}', JoiningType::Transparent),
+    ('\u{b3f}', '\u{b3f}', JoiningType::Transparent),
+    ('\u{b41}', '\u{b44}', JoiningType::Transparent),
+    ('\u{b4d}', '\u{b4d}', JoiningType::Transparent),
+    ('\u{b55}', '\u{b56}', JoiningType::Transparent),
+    ('\u{b62}', '\u{b63}', JoiningType::Transparent),
+    ('\u{b82}', '\u{b82}', JoiningType::Transparent),
+    ('\u{bc0}', '\u{bc0}', JoiningType::Transparent),
+    ('\u{bcd}', '\u{bcd}', JoiningType::Transparent),
+    ('\u{c00}', '\u{c00}', JoiningType::Transparent),
+    ('\u{c04}', '\u{c04}', JoiningType::Transparent),
+    ('\u{c3c}', '\u{c3c}', JoiningType::Transparent),
+    ('\u{c3e}', '\u{c40}', JoiningType::Transparent),
+    ('\u{c46}', '\u{c48}', JoiningType::Transparent),
+    ('\u{c4a}', '\u{c4d}', JoiningType::Transparent),
+    ('\u{c55}', '\u{c56}', JoiningType::Transparent),
+    ('\u{c62}', '\u{c63}', JoiningType::Transparent),
+    ('\u{c81}', '\u{c81}', JoiningType::Transparent),
+    ('\u{cbc}', '\u{cbc}', JoiningType::Transparent),
+    ('\u{cbf}', '\u{cbf}', JoiningType::Transparent),
+    ('\u{cc6}', '\u{cc6}', JoiningType::Transparent),
+    ('\u{ccc}', '\u{ccd}', JoiningType::Transparent),
+    ('\u{ce2}', '\u{ce3}', JoiningType::Transparent),
+    ('\u{d00}', '\u{d01}', JoiningType::Transparent),
+    ('\u{d3b}', '\u{d3c}', JoiningType::Transparent),
+    ('\u{d41}', '\u{d44}', JoiningType::Transparent),
+    ('\u{d4d}', '\u{d4d}', JoiningType::Transparent),
+    ('\u{d62}', '\u{d63}', JoiningType::Transparent),
+    ('\u{d81}', '\u{d81}', JoiningType::Transparent),
+    ('\u{dca}', '\u{dca}', JoiningType::Transparent),
+    ('\u{dd2}', '\u{dd4}', JoiningType::Transparent),
+    ('\u{dd6}', '\u{dd6}', JoiningType::Transparent),
+    ('\u{e31}', '\u{e31}', JoiningType::Transparent),
+    ('\u{e34}', '\u{e3a}', JoiningType::Transparent),
+    ('\u{e47}', '\u{e4e}', JoiningType::Transparent),
+    ('\u{eb1}', '\u{eb1}', JoiningType::Transparent),
+    ('\u{eb4}', '\u{ebc}', JoiningType::Transparent),
+    ('\u{ec8}', '\u{ece}', JoiningType::Transparent),
+    ('\u{f18}', '\u{f19}', JoiningType::Transparent),
+    ('\u{f35}', '\u{f35}', JoiningType::Transparent),
+    ('\u{f37}', '\u{f37}', JoiningType::Transparent),
+    ('\u{f39}', '\u{f39}', JoiningType::Transparent),
+    ('\u{f71}', '\u{f7e}', JoiningType::Transparent),
+    ('\u{f80}', '\u{f84}', JoiningType::Transparent),
+    ('\u{f86}', '\u{f87}', JoiningType::Transparent),
+    ('\u{f8d}', '\u{f97}', JoiningType::Transparent),
+    ('\u{f99}', '\u{fbc}', JoiningType::Transparent),
+    ('\u{fc6}', '\u{fc6}', JoiningType::Transparent),
+    ('\u{102d}', '\u{1030}', JoiningType::Transparent),
+    ('\u{1032}', '\u{1037}', JoiningType::Transparent),
+    ('\u{1039}', '\u{103a}', JoiningType::Transparent),
+    ('\u{103d}', '\u{103e}', JoiningType::Transparent),
+    ('\u{1058}', '\u{1059}', JoiningType::Transparent),
+    ('\u{105e}', '\u{1060}', JoiningType::Transparent),
+    ('\u{1071}', '\u{1074}', JoiningType::Transparent),
+    ('\u{1082}', '\u{1082}', JoiningType::Transparent),
+    ('\u{1085}', '\u{1086}', JoiningType::Transparent),
+    ('\u{108d}', '\u{108d}', JoiningType::Transparent),
+    ('\u{109d}', '\u{109d}', JoiningType::Transparent),
+    ('\u{135d}', '\u{135f}', JoiningType::Transparent),
+    ('\u{1712}', '\u{1714}', JoiningType::Transparent),
+    ('\u{1732}', '\u{1733}', JoiningType::Transparent),
+    ('\u{1752}', '\u{1753}', JoiningType::Transparent),
+    ('\u{1772}', '\u{1773}', JoiningType::Transparent),
+    ('\u{17b4}', '\u{17b5}', JoiningType::Transparent),
+    ('\u{17b7}', '\u{17bd}', JoiningType::Transparent),
+    ('\u{17c6}', '\u{17c6}', JoiningType::Transparent),
+    ('\u{17c9}', '\u{17d3}', JoiningType::Transparent),
+    ('\u{17dd}', '\u{17dd}', JoiningType::Transparent),
+    ('\u{1807}', '\u{1807}', JoiningType::Dual),
+    ('\u{180b}', '\u{180d}', JoiningType::Transparent),
+    ('\u{180f}', '\u{180f}', JoiningType::Transparent),
+    ('\u{1820}', '\u{1878}', JoiningType::Dual),
+    ('\u{1885}', '\u{1886}', JoiningType::Transparent),
+    ('\u{1887}', '\u{18a8}', JoiningType::Dual),
+    ('\u{18a9}', '\u{18a9}', JoiningType::Transparent),
+    ('\u{18aa}', '\u{18aa}', JoiningType::Dual),
+    ('\u{1920}', '\u{1922}', JoiningType::Transparent),
+    ('\u{1927}', '\u{1928}', JoiningType::Transparent),
+    ('\u{1932}', '\u{1932}', JoiningType::Transparent),
+    ('\u{1939}', '\u{193b}', JoiningType::Transparent),
+    ('\u{1a17}', '\u{1a18}', JoiningType::Transparent),
+    ('\u{1a1b}', '\u{1a1b}', JoiningType::Transparent),
+    ('\u{1a56}', '\u{1a56}', JoiningType::Transparent),
+    ('\u{1a58}', '\u{1a5e}', JoiningType::Transparent),
+    ('\u{1a60}', '\u{1a60}', JoiningType::Transparent),
+    ('\u{1a62}', '\u{1a62}', JoiningType::Transparent),
+    ('\u{1a65}', '\u{1a6c}', JoiningType::Transparent),
+    ('\u{1a73}', '\u{1a7c}', JoiningType::Transparent),
+    ('\u{1a7f}', '\u{1a7f}', JoiningType::Transparent),
+    ('\u{1ab0}', '\u{1add}', JoiningType::Transparent),
+    ('\u{1ae0}', '\u{1aeb}', JoiningType::Transparent),
+    ('\u{1b00}', '\u{1b03}', JoiningType::Transparent),
+    ('\u{1b34}', '\u{1b34}', JoiningType::Transparent),
+    ('\u{1b36}', '\u{1b3a}', JoiningType::Transparent),
+    ('\u{1b3c}', '\u{1b3c}', JoiningType::Transparent),
+    ('\u{1b42}', '\u{1b42}', JoiningType::Transparent),
+    ('\u{1b6b}', '\u{1b73}', JoiningType::Transparent),
+    ('\u{1b80}', '\u{1b81}', JoiningType::Transparent),
+    ('\u{1ba2}', '\u{1ba5}', JoiningType::Transparent),
+    ('\u{1ba8}', '\u{1ba9}', JoiningType::Transparent),
+    ('\u{1bab}', '\u{1bad}', JoiningType::Transparent),
+    ('\u{1be6}', '\u{1be6}', JoiningType::Transparent),
+    ('\u{1be8}', '\u{1be9}', JoiningType::Transparent),
+    ('\u{1bed}', '\u{1bed}', JoiningType::Transparent),
+    ('\u{1bef}', '\u{1bf1}', JoiningType::Transparent),
+    ('\u{1c2c}', '\u{1c33}', JoiningType::Transparent),
+    ('\u{1c36}', '\u{1c37}', JoiningType::Transparent),
+    ('\u{1cd0}', '\u{1cd2}', JoiningType::Transparent),
+    ('\u{1cd4}', '\u{1ce0}', JoiningType::Transparent),
+    ('\u{1ce2}', '\u{1ce8}', JoiningType::Transparent),
+    ('\u{1ced}', '\u{1ced}', JoiningType::Transparent),
+    ('\u{1cf4}', '\u{1cf4}', JoiningType::Transparent),
+    ('\u{1cf8}', '\u{1cf9}', JoiningType::Transparent),
+    ('\u{1dc0}', '\u{1dff}', JoiningType::Transparent),
+    ('\u{200b}', '\u{200b}', JoiningType::Transparent),
+    ('\u{200e}', '\u{200f}', JoiningType::Transparent),
+    ('\u{202a}', '\u{202e}', JoiningType::Transparent),
+    ('\u{2060}', '\u{2064}', JoiningType::Transparent),
+    ('\u{206a}', '\u{206f}', JoiningType::Transparent),
+    ('\u{20d0}', '\u{20f0}', JoiningType::Transparent),
+    ('\u{2cef}', '\u{2cf1}', JoiningType::Transparent),
+    ('\u{2d7f}', '\u{2d7f}', JoiningType::Transparent),
+    ('\u{2de0}', '\u{2dff}', JoiningType::Transparent),
+    ('\u{302a}', '\u{302d}', JoiningType::Transparent),
+    ('\u{3099}', '\u{309a}', JoiningType::Transparent),
+    ('\u{a66f}', '\u{a672}', JoiningType::Transparent),
+    ('\u{a674}', '\u{a67d}', JoiningType::Transparent),
+    ('\u{a69e}', '\u{a69f}', JoiningType::Transparent),
+    ('\u{a6f0}', '\u{a6f1}', JoiningType::Transparent),
+    ('\u{a802}', '\u{a802}', JoiningType::Transparent),
+    ('\u{a806}', '\u{a806}', JoiningType::Transparent),
+    ('\u{a80b}', '\u{a80b}', JoiningType::Transparent),
+    ('\u{a825}', '\u{a826}', JoiningType::Transparent),
+    ('\u{a82c}', '\u{a82c}', JoiningType::Transparent),
+    ('\u{a840}', '\u{a871}', JoiningType::Dual),
+    ('\u{a872}', '\u{a872}', JoiningType::Left),
+    ('\u{a8c4}', '\u{a8c5}', JoiningType::Transparent),
+    ('\u{a8e0}', '\u{a8f1}', JoiningType::Transparent),
+    ('\u{a8ff}', '\u{a8ff}', JoiningType::Transparent),
+    ('\u{a926}', '\u{a92d}', JoiningType::Transparent),
+    ('\u{a947}', '\u{a951}', JoiningType::Transparent),
+    ('\u{a980}', '\u{a982}', JoiningType::Transparent),
+    ('\u{a9b3}', '\u{a9b3}', JoiningType::Transparent),
+    ('\u{a9b6}', '\u{a9b9}', JoiningType::Transparent),
+    ('\u{a9bc}', '\u{a9bd}', JoiningType::Transparent),
+    ('\u{a9e5}', '\u{a9e5}', JoiningType::Transparent),
+    ('\u{aa29}', '\u{aa2e}', JoiningType::Transparent),
+    ('\u{aa31}', '\u{aa32}', JoiningType::Transparent),
+    ('\u{aa35}', '\u{aa36}', JoiningType::Transparent),
+    ('\u{aa43}', '\u{aa43}', JoiningType::Transparent),
+    ('\u{aa4c}', '\u{aa4c}', JoiningType::Transparent),
+    ('\u{aa7c}', '\u{aa7c}', JoiningType::Transparent),
+    ('\u{aab0}', '\u{aab0}', JoiningType::Transparent),
+    ('\u{aab2}', '\u{aab4}', JoiningType::Transparent),
+    ('\u{aab7}', '\u{aab8}', JoiningType::Transparent),
+    ('\u{aabe}', '\u{aabf}', JoiningType::Transparent),
+    ('\u{aac1}', '\u{aac1}', JoiningType::Transparent),
+    ('\u{aaec}', '\u{aaed}', JoiningType::Transparent),
+    ('\u{aaf6}', '\u{aaf6}', JoiningType::Transparent),
+    ('\u{abe5}', '\u{abe5}', JoiningType::Transparent),
+    ('\u{abe8}', '\u{abe8}', JoiningType::Transparent),
+    ('\u{abed}', '\u{abed}', JoiningType::Transparent),
+    ('\u{fb1e}', '\u{fb1e}', JoiningType::Transparent),
+    ('\u{fe00}', '\u{fe0f}', JoiningType::Transparent),
+    ('\u{fe20}', '\u{fe2f}', JoiningType::Transparent),
+    ('\u{feff}', '\u{feff}', JoiningType::Transparent),
+    ('\u{fff9}', '\u{fffb}', JoiningType::Transparent),
+    ('\u{101fd}', '\u{101fd}', JoiningType::Transparent),
+    ('\u{102e0}', '\u{102e0}', JoiningType::Transparent),
+    ('\u{10376}', '\u{1037a}', JoiningType::Transparent),
+    ('\u{10a01}', '\u{10a03}', JoiningType::Transparent),
+    ('\u{10a05}', '\u{10a06}', JoiningType::Transparent),
+    ('\u{10a0c}', '\u{10a0f}', JoiningType::Transparent),
+    ('\u{10a38}', '\u{10a3a}', JoiningType::Transparent),
+    ('\u{10a3f}', '\u{10a3f}', JoiningType::Transparent),
+    ('\u{10ac0}', '\u{10ac4}', JoiningType::Dual),
+    ('\u{10ac5}', '\u{10ac5}', JoiningType::Right),
+    ('\u{10ac7}', '\u{10ac7}', JoiningType::Right),
+    ('\u{10ac9}', '\u{10aca}', JoiningType::Right),
+    ('\u{10acd}', '\u{10acd}', JoiningType::Left),
+    ('\u{10ace}', '\u{10ad2}', JoiningType::Right),
+    ('\u{10ad3}', '\u{10ad6}', JoiningType::Dual),
+    ('\u{10ad7}', '\u{10ad7}', JoiningType::Left),
+    ('\u{10ad8}', '\u{10adc}', JoiningType::Dual),
+    ('\u{10add}', '\u{10add}', JoiningType::Right),
+    ('\u{10ade}', '\u{10ae0}', JoiningType::Dual),
+    ('\u{10ae1}', '\u{10ae1}', JoiningType::Right),
+    ('\u{10ae4}', '\u{10ae4}', JoiningType::Right),
+    ('\u{10ae5}', '\u{10ae6}', JoiningType::Transparent),
+    ('\u{10aeb}', '\u{10aee}', JoiningType::Dual),
+    ('\u{10aef}', '\u{10aef}', JoiningType::Right),
+    ('\u{10b80}', '\u{10b80}', JoiningType::Dual),
+    ('\u{10b81}', '\u{10b81}', JoiningType::Right),
+    ('\u{10b82}', '\u{10b82}', JoiningType::Dual),
+    ('\u{10b83}', '\u{10b85}', JoiningType::Right),
+    ('\u{10b86}', '\u{10b88}', JoiningType::Dual),
+    ('\u{10b89}', '\u{10b89}', JoiningType::Right),
+    ('\u{10b8a}', '\u{10b8b}', JoiningType::Dual),
+    ('\u{10b8c}', '\u{10b8c}', JoiningType::Right),
+    ('\u{10b8d}', '\u{10b8d}', JoiningType::Dual),
+    ('\u{10b8e}', '\u{10b8f}', JoiningType::Right),
+    ('\u{10b90}', '\u{10b90}', JoiningType::Dual),
+    ('\u{10b91}', '\u{10b91}', JoiningType::Right),
+    ('\u{10ba9}', '\u{10bac}', JoiningType::Right),
+    ('\u{10bad}', '\u{10bae}', JoiningType::Dual),
+    ('\u{10d00}', '\u{10d00}', JoiningType::Left),
+    ('\u{10d01}', '\u{10d21}', JoiningType::Dual),
+    ('\u{10d22}', '\u{10d22}', JoiningType::Right),
+    ('\u{10d23}', '\u{10d23}', JoiningType::Dual),
+    ('\u{10d24}', '\u{10d27}', JoiningType::Transparent),
+    ('\u{10d69}', '\u{10d6d}', JoiningType::Transparent),
+    ('\u{10eab}', '\u{10eac}', JoiningType::Transparent),
+    ('\u{10ec2}', '\u{10ec2}', JoiningType::Right),
+    ('\u{10ec3}', '\u{10ec4}', JoiningType::Dual),
+    ('\u{10ec6}', '\u{10ec7}', JoiningType::Dual),
+    ('\u{10efa}', '\u{10eff}', JoiningType::Transparent),
+    ('\u{10f30}', '\u{10f32}', JoiningType::Dual),
+    ('\u{10f33}', '\u{10f33}', JoiningType::Right),
+    ('\u{10f34}', '\u{10f44}', JoiningType::Dual),
+    ('\u{10f46}', '\u{10f50}', JoiningType::Transparent),
+    ('\u{10f51}', '\u{10f53}', JoiningType::Dual),
+    ('\u{10f54}', '\u{10f54}', JoiningType::Right),
+    ('\u{10f70}', '\u{10f73}', JoiningType::Dual),
+    ('\u{10f74}', '\u{10f75}', JoiningType::Right),
+    ('\u{10f76}', '\u{10f81}', JoiningType::Dual),
+    ('\u{10f82}', '\u{10f85}', JoiningType::Transparent),
+    ('\u{10fb0}', '\u{10fb0}', JoiningType::Dual),
+    ('\u{10fb2}', '\u{10fb3}', JoiningType::Dual),
+    ('\u{10fb4}', '\u{10fb6}', JoiningType::Right),
+    ('\u{10fb8}', '\u{10fb8}', JoiningType::Dual),
+    ('\u{10fb9}', '\u{10fba}', JoiningType::Right),
+    ('\u{10fbb}', '\u{10fbc}', JoiningType::Dual),
+    ('\u{10fbd}', '\u{10fbd}', JoiningType::Right),
+    ('\u{10fbe}', '\u{10fbf}', JoiningType::Dual),
+    ('\u{10fc1}', '\u{10fc1}', JoiningType::Dual),
+    ('\u{10fc2}', '\u{10fc3}', JoiningType::Right),
+    ('\u{10fc4}', '\u{10fc4}', JoiningType::Dual),
+    ('\u{10fc9}', '\u{10fc9}', JoiningType::Right),
+    ('\u{10fca}', '\u{10fca}', JoiningType::Dual),
+    ('\u{10fcb}', '\u{10fcb}', JoiningType::Left),
+    ('\u{11001}', '\u{11001}', JoiningType::Transparent),
+    ('\u{11038}', '\u{11046}', JoiningType::Transparent),
+    ('\u{11070}', '\u{11070}', JoiningType::Transparent),
+    ('\u{11073}', '\u{11074}', JoiningType::Transparent),
+    ('\u{1107f}', '\u{11081}', JoiningType::Transparent),
+    ('\u{110b3}', '\u{110b6}', JoiningType::Transparent),
+    ('\u{110b9}', '\u{110ba}', JoiningType::Transparent),
+    ('\u{110c2}', '\u{110c2}', JoiningType::Transparent),
+    ('\u{11100}', '\u{11102}', JoiningType::Transparent),
+    ('\u{11127}', '\u{1112b}', JoiningType::Transparent),
+    ('\u{1112d}', '\u{11134}', JoiningType::Transparent),
+    ('\u{11173}', '\u{11173}', JoiningType::Transparent),
+    ('\u{11180}', '\u{11181}', JoiningType::Transparent),
+    ('\u{111b6}', '\u{111be}', JoiningType::Transparent),
+    ('\u{111c9}', '\u{111cc}', JoiningType::Transparent),
+    ('\u{111cf}', '\u{111cf}', JoiningType::Transparent),
+    ('\u{1122f}', '\u{11231}', JoiningType::Transparent),
+    ('\u{11234}', '\u{11234}', JoiningType::Transparent),
+    ('\u{11236}', '\u{11237}', JoiningType::Transparent),
+    ('\u{1123e}', '\u{1123e}', JoiningType::Transparent),
+    ('\u{11241}', '\u{11241}', JoiningType::Transparent),
+    ('\u{112df}', '\u{112df}', JoiningType::Transparent),
+    ('\u{112e3}', '\u{112ea}', JoiningType::Transparent),
+    ('\u{11300}', '\u{11301}', JoiningType::Transparent),
+    ('\u{1133b}', '\u{1133c}', JoiningType::Transparent),
+    ('\u{11340}', '\u{11340}', JoiningType::Transparent),
+    ('\u{11366}', '\u{1136c}', JoiningType::Transparent),
+    ('\u{11370}', '\u{11374}', JoiningType::Transparent),
+    ('\u{113bb}', '\u{113c0}', JoiningType::Transparent),
+    ('\u{113ce}', '\u{113ce}', JoiningType::Transparent),
+    ('\u{113d0}', '\u{113d0}', JoiningType::Transparent),
+    ('\u{113d2}', '\u{113d2}', JoiningType::Transparent),
+    ('\u{113e1}', '\u{113e2}', JoiningType::Transparent),
+    ('\u{11438}', '\u{1143f}', JoiningType::Transparent),
+    ('\u{11442}', '\u{11444}', JoiningType::Transparent),
+    ('\u{11446}', '\u{11446}', JoiningType::Transparent),
+    ('\u{1145e}', '\u{1145e}', JoiningType::Transparent),
+    ('\u{114b3}', '\u{114b8}', JoiningType::Transparent),
+    ('\u{114ba}', '\u{114ba}', JoiningType::Transparent),
+    ('\u{114bf}', '\u{114c0}', JoiningType::Transparent),
+    ('\u{114c2}', '\u{114c3}', JoiningType::Transparent),
+    ('\u{115b2}', '\u{115b5}', JoiningType::Transparent),
+    ('\u{115bc}', '\u{115bd}', JoiningType::Transparent),
+    ('\u{115bf}', '\u{115c0}', JoiningType::Transparent),
+    ('\u{115dc}', '\u{115dd}', JoiningType::Transparent),
+    ('\u{11633}', '\u{1163a}', JoiningType::Transparent),
+    ('\u{1163d}', '\u{1163d}', JoiningType::Transparent),
+    ('\u{1163f}', '\u{11640}', JoiningType::Transparent),
+    ('\u{116ab}', '\u{116ab}', JoiningType::Transparent),
+    ('\u{116ad}', '\u{116ad}', JoiningType::Transparent),
+    ('\u{116b0}', '\u{116b5}', JoiningType::Transparent),
+    ('\u{116b7}', '\u{116b7}', JoiningType::Transparent),
+    ('\u{1171d}', '\u{1171d}', JoiningType::Transparent),
+    ('\u{1171f}', '\u{1171f}', JoiningType::Transparent),
+    ('\u{11722}', '\u{11725}', JoiningType::Transparent),
+    ('\u{11727}', '\u{1172b}', JoiningType::Transparent),
+    ('\u{1182f}', '\u{11837}', JoiningType::Transparent),
+    ('\u{11839}', '\u{1183a}', JoiningType::Transparent),
+    ('\u{1193b}', '\u{1193c}', JoiningType::Transparent),
+    ('\u{1193e}', '\u{1193e}', JoiningType::Transparent),
+    ('\u{11943}', '\u{11943}', JoiningType::Transparent),
+    ('\u{119d4}', '\u{119d7}', JoiningType::Transparent),
+    ('\u{119da}', '\u{119db}', JoiningType::Transparent),
+    ('\u{119e0}', '\u{119e0}', JoiningType::Transparent),
+    ('\u{11a01}', '\u{11a0a}', JoiningType::Transparent),
+    ('\u{11a33}', '\u{11a38}', JoiningType::Transparent),
+    ('\u{11a3b}', '\u{11a3e}', JoiningType::Transparent),
+    ('\u{11a47}', '\u{11a47}', JoiningType::Transparent),
+    ('\u{11a51}', '\u{11a56}', JoiningType::Transparent),
+    ('\u{11a59}', '\u{11a5b}', JoiningType::Transparent),
+    ('\u{11a8a}', '\u{11a96}', JoiningType::Transparent),
+    ('\u{11a98}', '\u{11a99}', JoiningType::Transparent),
+    ('\u{11b60}', '\u{11b60}', JoiningType::Transparent),
+    ('\u{11b62}', '\u{11b64}', JoiningType::Transparent),
+    ('\u{11b66}', '\u{11b66}', JoiningType::Transparent),
+    ('\u{11c30}', '\u{11c36}', JoiningType::Transparent),
+    ('\u{11c38}', '\u{11c3d}', JoiningType::Transparent),
+    ('\u{11c3f}', '\u{11c3f}', JoiningType::Transparent),
+    ('\u{11c92}', '\u{11ca7}', JoiningType::Transparent),
+    ('\u{11caa}', '\u{11cb0}', JoiningType::Transparent),
+    ('\u{11cb2}', '\u{11cb3}', JoiningType::Transparent),
+    ('\u{11cb5}', '\u{11cb6}', JoiningType::Transparent),
+    ('\u{11d31}', '\u{11d36}', JoiningType::Transparent),
+    ('\u{11d3a}', '\u{11d3a}', JoiningType::Transparent),
+    ('\u{11d3c}', '\u{11d3d}', JoiningType::Transparent),
+    ('\u{11d3f}', '\u{11d45}', JoiningType::Transparent),
+    ('\u{11d47}', '\u{11d47}', JoiningType::Transparent),
+    ('\u{11d90}', '\u{11d91}', JoiningType::Transparent),
+    ('\u{11d95}', '\u{11d95}', JoiningType::Transparent),
+    ('\u{11d97}', '\u{11d97}', JoiningType::Transparent),
+    ('\u{11ef3}', '\u{11ef4}', JoiningType::Transparent),
+    ('\u{11f00}', '\u{11f01}', JoiningType::Transparent),
+    ('\u{11f36}', '\u{11f3a}', JoiningType::Transparent),
+    ('\u{11f40}', '\u{11f40}', JoiningType::Transparent),
+    ('\u{11f42}', '\u{11f42}', JoiningType::Transparent),
+    ('\u{11f5a}', '\u{11f5a}', JoiningType::Transparent),
+    ('\u{13430}', '\u{13440}', JoiningType::Transparent),
+    ('\u{13447}', '\u{13455}', JoiningType::Transparent),
+    ('\u{1611e}', '\u{16129}', JoiningType::Transparent),
+    ('\u{1612d}', '\u{1612f}', JoiningType::Transparent),
+    ('\u{16af0}', '\u{16af4}', JoiningType::Transparent),
+    ('\u{16b30}', '\u{16b36}', JoiningType::Transparent),
+    ('\u{16f4f}', '\u{16f4f}', JoiningType::Transparent),
+    ('\u{16f8f}', '\u{16f92}', JoiningType::Transparent),
+    ('\u{16fe4}', '\u{16fe4}', JoiningType::Transparent),
+    ('\u{1bc9d}', '\u{1bc9e}', JoiningType::Transparent),
+    ('\u{1bca0}', '\u{1bca3}', JoiningType::Transparent),
+    ('\u{1cf00}', '\u{1cf2d}', JoiningType::Transparent),
+    ('\u{1cf30}', '\u{1cf46}', JoiningType::Transparent),
+    ('\u{1d167}', '\u{1d169}', JoiningType::Transparent),
+    ('\u{1d173}', '\u{1d182}', JoiningType::Transparent),
+    ('\u{1d185}', '\u{1d18b}', JoiningType::Transparent),
+    ('\u{1d1aa}', '\u{1d1ad}', JoiningType::Transparent),
+    ('\u{1d242}', '\u{1d244}', JoiningType::Transparent),
+    ('\u{1da00}', '\u{1da36}', JoiningType::Transparent),
+    ('\u{1da3b}', '\u{1da6c}', JoiningType::Transparent),
+    ('\u{1da75}', '\u{1da75}', JoiningType::Transparent),
+    ('\u{1da84}', '\u{1da84}', JoiningType::Transparent),
+    ('\u{1da9b}', '\u{1da9f}', JoiningType::Transparent),
+    ('\u{1daa1}', '\u{1daaf}', JoiningType::Transparent),
+    ('\u{1e000}', '\u{1e006}', JoiningType::Transparent),
+    ('\u{1e008}', '\u{1e018}', JoiningType::Transparent),
+    ('\u{1e01b}', '\u{1e021}', JoiningType::Transparent),
+    ('\u{1e023}', '\u{1e024}', JoiningType::Transparent),
+    ('\u{1e026}', '\u{1e02a}', JoiningType::Transparent),
+    ('\u{1e08f}', '\u{1e08f}', JoiningType::Transparent),
+    ('\u{1e130}', '\u{1e136}', JoiningType::Transparent),
+    ('\u{1e2ae}', '\u{1e2ae}', JoiningType::Transparent),
+    ('\u{1e2ec}', '\u{1e2ef}', JoiningType::Transparent),
+    ('\u{1e4ec}', '\u{1e4ef}', JoiningType::Transparent),
+    ('\u{1e5ee}', '\u{1e5ef}', JoiningType::Transparent),
+    ('\u{1e6e3}', '\u{1e6e3}', JoiningType::Transparent),
+    ('\u{1e6e6}', '\u{1e6e6}', JoiningType::Transparent),
+    ('\u{1e6ee}', '\u{1e6ef}', JoiningType::Transparent),
+    ('\u{1e6f5}', '\u{1e6f5}', JoiningType::Transparent),
+    ('\u{1e8d0}', '\u{1e8d6}', JoiningType::Transparent),
+    ('\u{1e900}', '\u{1e943}', JoiningType::Dual),
+    ('\u{1e944}', '\u{1e94b}', JoiningType::Transparent),
+    ('\u{e0001}', '\u{e0001}', JoiningType::Transparent),
+    ('\u{e0020}', '\u{e007f}', JoiningType::Transparent),
+    ('\u{e0100}', '\u{e01ef}', JoiningType::Transparent),
+];
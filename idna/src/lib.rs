@@ -32,13 +32,30 @@
 //! > that minimizes the impact of this transition for client software,
 //! > allowing client software to access domains that are valid under either system.
 
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
 #[macro_use]
 extern crate matches;
 
+#[cfg(feature = "std")]
+use std::string::String;
+
+#[cfg(feature = "alloc")]
 pub mod punycode;
+#[cfg(feature = "std")]
 mod uts46;
 
-pub use crate::uts46::{Config, Errors, Idna};
+#[cfg(feature = "std")]
+pub use crate::uts46::{
+    deviation_mapping, is_bidi_domain, map_chars, map_status, passes_bidi_rules, Config, ErrorKind,
+    ErrorKinds, Errors, Idna, MappingStatus, ToAsciiBatch, UNICODE_VERSION,
+};
 
 /// The [domain to ASCII](https://url.spec.whatwg.org/#concept-domain-to-ascii) algorithm.
 ///
@@ -47,14 +64,16 @@ pub use crate::uts46::{Config, Errors, Idna};
 /// and using Punycode as necessary.
 ///
 /// This process may fail.
+#[cfg(feature = "std")]
 pub fn domain_to_ascii(domain: &str) -> Result<String, uts46::Errors> {
-    Config::default().to_ascii(domain)
+    Config::whatwg_url_host().to_ascii(domain)
 }
 
 /// The [domain to ASCII](https://url.spec.whatwg.org/#concept-domain-to-ascii) algorithm,
 /// with the `beStrict` flag set.
+#[cfg(feature = "std")]
 pub fn domain_to_ascii_strict(domain: &str) -> Result<String, uts46::Errors> {
-    Config::default()
+    Config::whatwg_url_host()
         .use_std3_ascii_rules(true)
         .verify_dns_length(true)
         .to_ascii(domain)
@@ -68,6 +87,15 @@ pub fn domain_to_ascii_strict(domain: &str) -> Result<String, uts46::Errors> {
 ///
 /// This may indicate [syntax violations](https://url.spec.whatwg.org/#syntax-violation)
 /// but always returns a string for the mapped domain.
+#[cfg(feature = "std")]
 pub fn domain_to_unicode(domain: &str) -> (String, Result<(), uts46::Errors>) {
-    Config::default().to_unicode(domain)
+    Config::whatwg_url_host().to_unicode(domain)
+}
+
+/// The version of Unicode of the UTS #46 mapping table, as `(major, minor, update)`.
+///
+/// This changes when the table is regenerated, and with it the mapping of some domains.
+#[cfg(feature = "std")]
+pub fn unicode_version() -> (u8, u8, u8) {
+    UNICODE_VERSION
 }
@@ -0,0 +1,61 @@
+# Copyright 2013-2020 The rust-url developers.
+#
+# Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+# http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+# <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+# option. This file may not be copied, modified, or distributed
+# except according to those terms.
+
+# Run as: python make_joining_type_table.py DerivedJoiningType.txt > joining_type_table.rs
+# You can get the latest table from
+# http://www.unicode.org/Public/UCD/latest/ucd/extracted/DerivedJoiningType.txt
+
+import sys
+
+print('''\
+// Copyright 2013-2020 The rust-url developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Generated by make_joining_type_table.py
+''')
+
+# Only the joining types used by the ContextJ rules of RFC 5892 are kept,
+# everything else is treated as non-joining.
+kinds = {
+    'D': 'Dual',
+    'L': 'Left',
+    'R': 'Right',
+    'T': 'Transparent',
+}
+
+ranges = []
+for line in open(sys.argv[1] if len(sys.argv) > 1 else "DerivedJoiningType.txt"):
+    line = line.split('#')[0].strip()
+    if not line:
+        continue
+    codepoints, kind = [field.strip() for field in line.split(';')]
+    if kind not in kinds:
+        continue
+    if '..' in codepoints:
+        first, last = [int(c, 16) for c in codepoints.split('..')]
+    else:
+        first = last = int(codepoints, 16)
+    ranges.append((first, last, kind))
+
+ranges.sort()
+merged = []
+for first, last, kind in ranges:
+    if merged and merged[-1][1] + 1 == first and merged[-1][2] == kind:
+        merged[-1] = (merged[-1][0], last, kind)
+    else:
+        merged.append((first, last, kind))
+
+print("static JOINING_TYPE_TABLE: &[(char, char, JoiningType)] = &[")
+for first, last, kind in merged:
+    print("    ('\\u{%x}', '\\u{%x}', JoiningType::%s)," % (first, last, kinds[kind]))
+print("];")
@@ -0,0 +1,150 @@
+# Copyright 2013-2020 The rust-url developers.
+#
+# Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+# http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+# <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+# option. This file may not be copied, modified, or distributed
+# except according to those terms.
+
+# Run as: python make_script_table.py > script_table.rs
+# with the following files in the current directory:
+# http://www.unicode.org/Public/UCD/latest/ucd/Scripts.txt
+# http://www.unicode.org/Public/UCD/latest/ucd/ScriptExtensions.txt
+# http://www.unicode.org/Public/UCD/latest/ucd/PropertyValueAliases.txt
+
+print('''\
+// Copyright 2013-2020 The rust-url developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Generated by make_script_table.py
+''')
+
+# The Recommended scripts of UAX #31, Table 5, that can be mixed with Latin in
+# the Moderately Restrictive profile of UTS #39: all but Cyrillic and Greek.
+latin_companions = [
+    'Arab', 'Armn', 'Beng', 'Bopo', 'Deva', 'Ethi', 'Geor', 'Gujr', 'Guru',
+    'Hang', 'Hani', 'Hebr', 'Hira', 'Kana', 'Khmr', 'Knda', 'Laoo', 'Mlym',
+    'Mymr', 'Orya', 'Sinh', 'Taml', 'Telu', 'Thaa', 'Thai', 'Tibt',
+]
+
+# Scripts that are compatible with all others.
+universal = ['Zyyy', 'Zinh']
+
+# The augmented script sets of UTS #39, section 5.1.
+augmented = {
+    'Hani': ['Hanb', 'Jpan', 'Kore'],
+    'Hira': ['Jpan'],
+    'Kana': ['Jpan'],
+    'Hang': ['Kore'],
+    'Bopo': ['Hanb'],
+}
+
+
+def lines(filename):
+    for line in open(filename):
+        line = line.split('#')[0].strip()
+        if line:
+            yield [field.strip() for field in line.split(';')]
+
+
+def codepoints(field):
+    if '..' in field:
+        first, last = [int(c, 16) for c in field.split('..')]
+    else:
+        first = last = int(field, 16)
+    return range(first, last + 1)
+
+
+short_names = {}
+for fields in lines('PropertyValueAliases.txt'):
+    if fields[0] == 'sc':
+        short_names[fields[2]] = fields[1]
+
+# The scripts used by the ContextO rules of RFC 5892, by the Script property
+# rather than Script_Extensions.
+context_o_scripts = {
+    'Grek': 'Greek',
+    'Hebr': 'Hebrew',
+    'Hira': 'Hiragana',
+    'Kana': 'Katakana',
+    'Hani': 'Han',
+}
+context_o_ranges = []
+
+scripts = {}
+for field, script in lines('Scripts.txt'):
+    script = short_names.get(script, script)
+    for c in codepoints(field):
+        scripts[c] = set([script])
+    if script in context_o_scripts:
+        c = codepoints(field)
+        context_o_ranges.append((c[0], c[-1], context_o_scripts[script]))
+for field, extensions in lines('ScriptExtensions.txt'):
+    for c in codepoints(field):
+        scripts[c] = set(extensions.split())
+
+names = set(['Hanb', 'Jpan', 'Kore'])
+for c, s in scripts.items():
+    for script in list(s):
+        for extra in augmented.get(script, []):
+            s.add(extra)
+    names |= s
+names = sorted(names - set(universal))
+bits = dict((name, i) for i, name in enumerate(names))
+words = (len(names) + 63) // 64
+assert words <= 3, 'ScriptSet is too small'
+
+
+def script_set(s):
+    value = [0] * 3
+    for script in s:
+        value[bits[script] // 64] |= 1 << (bits[script] % 64)
+    return 'ScriptSet([%s])' % ', '.join('0x%x' % word for word in value)
+
+
+ranges = []
+for c in sorted(scripts):
+    s = scripts[c]
+    if s & set(universal):
+        continue
+    s = script_set(s)
+    if ranges and ranges[-1][1] + 1 == c and ranges[-1][2] == s:
+        ranges[-1][1] = c
+    else:
+        ranges.append([c, c, s])
+
+sets = sorted(set(s for _, _, s in ranges))
+indices = dict((s, i) for i, s in enumerate(sets))
+
+print('const LATIN: ScriptSet = %s;' % script_set(['Latn']))
+print('const LATIN_COMPANIONS: ScriptSet = %s;' %
+      script_set(latin_companions + ['Hanb', 'Jpan', 'Kore']))
+print()
+print('static SCRIPT_SETS: &[ScriptSet] = &[')
+for s in sets:
+    print('    %s,' % s)
+print('];')
+print()
+print('static SCRIPT_TABLE: &[(char, char, u16)] = &[')
+for first, last, s in ranges:
+    print("    ('\\u{%x}', '\\u{%x}', %d)," % (first, last, indices[s]))
+print('];')
+
+context_o_ranges.sort()
+merged = []
+for first, last, script in context_o_ranges:
+    if merged and merged[-1][1] + 1 == first and merged[-1][2] == script:
+        merged[-1] = (merged[-1][0], last, script)
+    else:
+        merged.append((first, last, script))
+
+print()
+print('static CONTEXT_O_SCRIPT_TABLE: &[(char, char, ContextOScript)] = &[')
+for first, last, script in merged:
+    print("    ('\\u{%x}', '\\u{%x}', ContextOScript::%s)," % (first, last, script))
+print('];')
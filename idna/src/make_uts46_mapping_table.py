@@ -58,8 +58,11 @@ def rust_slice(s):
     return "(StringTableSlice { byte_start_lo: %d, byte_start_hi: %d, byte_len: %d })" % (start_lo, start_hi, length)
 
 ranges = []
+version = None
 
 for line in txt:
+    if line.startswith('# Version:'):
+        version = tuple(int(v) for v in line.split(':')[1].strip().split('.'))
     # remove comments
     line, _, _ = line.partition('#')
     # skip empty lines
@@ -152,6 +155,10 @@ optimized_ranges = list(merge_single_char_ranges(optimized_ranges))
 
 SINGLE_MARKER = 1 << 15
 
+assert version is not None, "IdnaMappingTable.txt has no Version line"
+print("/// The version of Unicode of the UTS #46 mapping table, as `(major, minor, update)`.")
+print("pub const UNICODE_VERSION: (u8, u8, u8) = (%d, %d, %d);\n" % version)
+
 print("static TABLE: &[(char, u16)] = &[")
 
 offset = 0
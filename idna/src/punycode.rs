@@ -13,29 +13,108 @@
 //! `encode_str` and `decode_to_string` provide convenience wrappers
 //! that convert from and to Rust’s UTF-8 based `str` and `String` types.
 
-use std::char;
-use std::u32;
-
-// Bootstring parameters for Punycode
-static BASE: u32 = 36;
-static T_MIN: u32 = 1;
-static T_MAX: u32 = 26;
-static SKEW: u32 = 38;
-static DAMP: u32 = 700;
-static INITIAL_BIAS: u32 = 72;
-static INITIAL_N: u32 = 0x80;
+use alloc::{string::String, vec::Vec};
+use core::char;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error as StdError;
+
 static DELIMITER: char = '-';
 
-#[inline]
-fn adapt(mut delta: u32, num_points: u32, first_time: bool) -> u32 {
-    delta /= if first_time { DAMP } else { 2 };
-    delta += delta / num_points;
-    let mut k = 0;
-    while delta > ((BASE - T_MIN) * T_MAX) / 2 {
-        delta /= BASE - T_MIN;
-        k += BASE;
+// `u32::MAX` and `usize::MAX` are associated constants since 1.43, MSRV is 1.36
+const U32_MAX: u32 = !0;
+pub(crate) const USIZE_MAX: usize = !0;
+
+/// The DNS limit on the length of domain name labels, in bytes.
+///
+/// For Punycode labels this includes the `xn--` prefix, see `encode_checked`.
+pub const PUNYCODE_MAX_LABEL_BYTES: usize = 63;
+
+/// Parameters of the [Bootstring](https://tools.ietf.org/html/rfc3492#section-3) algorithm,
+/// of which Punycode is one instance.
+///
+/// Digits are always `a-z` (0 to 25) then `0-9` (26 to 35), and the delimiter is always `-`.
+/// `encode_with` and `decode_with` return None for parameters that are not `is_valid`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BootstringParams {
+    /// The number of digits, at most 36.
+    pub base: u32,
+    /// The smallest threshold of a digit, at least 1.
+    pub tmin: u32,
+    /// The largest threshold of a digit, at least `tmin` and less than `base`.
+    pub tmax: u32,
+    /// Bias adaptation skew, at least 1.
+    pub skew: u32,
+    /// Bias adaptation damping factor for the first delta, at least 2.
+    pub damp: u32,
+    /// The bias before the first delta.
+    /// `initial_bias % base` must be at most `base - tmin`.
+    pub initial_bias: u32,
+    /// The first non-basic code point, at most 0x80.
+    /// Basic code points are those below it, and are always ASCII.
+    pub initial_n: u32,
+}
+
+/// The [Punycode](https://tools.ietf.org/html/rfc3492#section-5) profile of Bootstring.
+pub struct Punycode(());
+
+impl Punycode {
+    /// The RFC 3492 parameters for Punycode.
+    pub const DEFAULT: BootstringParams = BootstringParams {
+        base: 36,
+        tmin: 1,
+        tmax: 26,
+        skew: 38,
+        damp: 700,
+        initial_bias: 72,
+        initial_n: 0x80,
+    };
+}
+
+impl BootstringParams {
+    /// Whether these parameters satisfy the constraints of
+    /// [RFC 3492 section 4](https://tools.ietf.org/html/rfc3492#section-4),
+    /// and the limits on `base` and `initial_n` documented on each field.
+    pub fn is_valid(&self) -> bool {
+        self.base <= 36
+            && self.initial_n <= 0x80
+            && self.tmin >= 1
+            && self.tmin <= self.tmax
+            && self.tmax < self.base
+            && self.skew >= 1
+            && self.damp >= 2
+            && self.initial_bias % self.base <= self.base - self.tmin
+    }
+
+    #[inline]
+    fn threshold(&self, k: u32, bias: u32) -> u32 {
+        if k <= bias {
+            self.tmin
+        } else if k >= bias.saturating_add(self.tmax) {
+            self.tmax
+        } else {
+            k - bias
+        }
+    }
+
+    #[inline]
+    fn adapt(&self, mut delta: u32, num_points: u32, first_time: bool) -> u32 {
+        delta /= if first_time { self.damp } else { 2 };
+        delta += delta / num_points;
+        let mut k = 0;
+        while delta > ((self.base - self.tmin) * self.tmax) / 2 {
+            delta /= self.base - self.tmin;
+            k += self.base;
+        }
+        k + (((self.base - self.tmin + 1) * delta) / delta.saturating_add(self.skew))
+    }
+}
+
+/// Same as `Punycode::DEFAULT`.
+impl Default for BootstringParams {
+    fn default() -> Self {
+        Punycode::DEFAULT
     }
-    k + (((BASE - T_MIN + 1) * delta) / (delta + SKEW))
 }
 
 /// Convert Punycode to an Unicode `String`.
@@ -50,19 +129,162 @@ pub fn decode_to_string(input: &str) -> Option<String> {
 ///
 /// Return None on malformed input or overflow.
 /// Overflow can only happen on inputs that take more than
-/// 63 encoded bytes, the DNS limit on domain name labels.
+/// `PUNYCODE_MAX_LABEL_BYTES` encoded bytes, the DNS limit on domain name labels.
 pub fn decode(input: &str) -> Option<Vec<char>> {
-    Some(Decoder::default().decode(input).ok()?.collect())
+    let mut output = Vec::new();
+    decode_into(input, &mut output).ok()?;
+    Some(output)
+}
+
+/// Convert Punycode to Unicode, reusing the `output` buffer.
+///
+/// `output` is cleared first and keeps its capacity. On error, `output` is left empty.
+/// Only the output buffer is reused: positions of non-basic code points are still
+/// collected in a temporary buffer, which is not allocated for all-ASCII input.
+pub fn decode_into(input: &str, output: &mut Vec<char>) -> Result<(), PunycodeError> {
+    output.clear();
+    output.extend(Decoder::default().decode(input)?);
+    Ok(())
+}
+
+/// Decode Punycode into its basic code points and its insertions, without merging them.
+///
+/// The basic code points are the ASCII ones before the last delimiter, in order.
+/// Each insertion is a non-basic code point with its index in the decoded output,
+/// sorted by index. Inserting them in order into the basic code points gives `decode`.
+///
+/// Return None on malformed input or overflow.
+///
+/// ```
+/// use idna::punycode::decode_parts;
+///
+/// let (basic, insertions) = decode_parts("bcher-kva").unwrap();
+/// assert_eq!(basic, "bcher");
+/// assert_eq!(insertions, [(1, 'ü')]);
+/// ```
+pub fn decode_parts(input: &str) -> Option<(String, Vec<(usize, char)>)> {
+    let mut decoder = Decoder::default();
+    let basic = decoder
+        .decode_insertions(&Punycode::DEFAULT, input, USIZE_MAX)
+        .ok()?;
+    Some((basic.into(), decoder.insertions))
+}
+
+/// Convert a Bootstring encoding with the given parameters to Unicode.
+///
+/// Return None on invalid parameters, malformed input or overflow.
+pub fn decode_with(params: &BootstringParams, input: &str) -> Option<Vec<char>> {
+    if !params.is_valid() {
+        return None;
+    }
+    Some(
+        Decoder::default()
+            .decode_with(params, input, USIZE_MAX)
+            .ok()?
+            .collect(),
+    )
+}
+
+/// Convert Punycode to Unicode, producing at most `max_code_points` code points.
+///
+/// Decoding stops as soon as the output would get longer,
+/// so that overly long input is rejected without doing the full decoding work.
+/// Passing `PUNYCODE_MAX_LABEL_BYTES` enforces the DNS limit on domain name labels,
+/// since every decoded code point takes at least one encoded byte.
+///
+/// Return None on malformed input, overflow, or output longer than `max_code_points`.
+pub fn decode_bounded(input: &str, max_code_points: usize) -> Option<Vec<char>> {
+    Some(
+        Decoder::default()
+            .decode_bounded(input, max_code_points)
+            .ok()?
+            .collect(),
+    )
+}
+
+/// Convert Punycode to Unicode, rejecting non-canonical encodings.
+///
+/// The decoded output is encoded again and must be byte-for-byte equal to the input,
+/// so that there is exactly one accepted encoding of any given label.
+/// In particular, this rejects uppercase digits (`A-Z` after the last delimiter)
+/// and any redundant zero padding of the variable-length integers,
+/// or other forms that `encode` would not produce.
+///
+/// Return None on malformed, non-canonical input or overflow.
+pub fn decode_strict(input: &str) -> Option<Vec<char>> {
+    let output = decode(input)?;
+    if is_canonical(output.iter().copied(), input) {
+        Some(output)
+    } else {
+        None
+    }
+}
+
+/// Whether encoding `decoded` gives exactly `encoded`, without allocating.
+pub(crate) fn is_canonical<I>(decoded: I, encoded: &str) -> bool
+where
+    I: Iterator<Item = char> + Clone,
+{
+    struct Matcher<'a>(&'a str);
+
+    impl<'a> fmt::Write for Matcher<'a> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            if self.0.starts_with(s) {
+                self.0 = &self.0[s.len()..];
+                Ok(())
+            } else {
+                Err(fmt::Error)
+            }
+        }
+    }
+
+    let mut matcher = Matcher(encoded);
+    encode_into(decoded, &mut matcher).is_ok() && matcher.0.is_empty()
 }
 
 #[derive(Default)]
 pub(crate) struct Decoder {
     insertions: Vec<(usize, char)>,
+    free_slots: FenwickTree,
 }
 
 impl Decoder {
     /// Split the input iterator and return a Vec with insertions of encoded characters
-    pub(crate) fn decode<'a>(&'a mut self, input: &'a str) -> Result<Decode<'a>, ()> {
+    pub(crate) fn decode<'a>(&'a mut self, input: &'a str) -> Result<Decode<'a>, PunycodeError> {
+        self.decode_with(&Punycode::DEFAULT, input, USIZE_MAX)
+    }
+
+    /// Same as `decode`, but fail as soon as the output would exceed `max_code_points`.
+    pub(crate) fn decode_bounded<'a>(
+        &'a mut self,
+        input: &'a str,
+        max_code_points: usize,
+    ) -> Result<Decode<'a>, PunycodeError> {
+        self.decode_with(&Punycode::DEFAULT, input, max_code_points)
+    }
+
+    fn decode_with<'a>(
+        &'a mut self,
+        params: &BootstringParams,
+        input: &'a str,
+        max_code_points: usize,
+    ) -> Result<Decode<'a>, PunycodeError> {
+        let base = self.decode_insertions(params, input, max_code_points)?;
+        Ok(Decode {
+            merge: Merge::new(base),
+            insertions: &self.insertions,
+            len: base.len() + self.insertions.len(),
+        })
+    }
+
+    /// Fill `self.insertions` with the sorted final positions of non-basic code points,
+    /// and return the basic code points.
+    fn decode_insertions<'i>(
+        &mut self,
+        params: &BootstringParams,
+        input: &'i str,
+        max_code_points: usize,
+    ) -> Result<&'i str, PunycodeError> {
         self.insertions.clear();
         // Handle "basic" (ASCII) code points.
         // They are encoded as-is before the last delimiter, if any.
@@ -78,20 +300,23 @@ impl Decoder {
             ),
         };
 
-        if !base.is_ascii() {
-            return Err(());
+        if let Some(c) = base.chars().find(|&c| c as u32 >= params.initial_n) {
+            return Err(PunycodeError::InvalidCodePoint(c as u32));
         }
 
         let base_len = base.len();
+        if base_len > max_code_points {
+            return Err(PunycodeError::TooLong);
+        }
         let mut length = base_len as u32;
-        let mut code_point = INITIAL_N;
-        let mut bias = INITIAL_BIAS;
+        let mut code_point = params.initial_n;
+        let mut bias = params.initial_bias;
         let mut i = 0;
         let mut iter = input.bytes();
         loop {
             let previous_i = i;
             let mut weight = 1;
-            let mut k = BASE;
+            let mut k = params.base;
             let mut byte = match iter.next() {
                 None => break,
                 Some(byte) => byte,
@@ -104,36 +329,34 @@ impl Decoder {
                     byte @ b'0'..=b'9' => byte - b'0' + 26,
                     byte @ b'A'..=b'Z' => byte - b'A',
                     byte @ b'a'..=b'z' => byte - b'a',
-                    _ => return Err(()),
+                    _ => return Err(PunycodeError::InvalidDigit(byte)),
                 } as u32;
-                if digit > (u32::MAX - i) / weight {
-                    return Err(()); // Overflow
+                if digit >= params.base {
+                    return Err(PunycodeError::InvalidDigit(byte));
+                }
+                if digit > (U32_MAX - i) / weight {
+                    return Err(PunycodeError::Overflow);
                 }
                 i += digit * weight;
-                let t = if k <= bias {
-                    T_MIN
-                } else if k >= bias + T_MAX {
-                    T_MAX
-                } else {
-                    k - bias
-                };
+                let t = params.threshold(k, bias);
                 if digit < t {
                     break;
                 }
-                if weight > u32::MAX / (BASE - t) {
-                    return Err(()); // Overflow
+                if weight > U32_MAX / (params.base - t) {
+                    return Err(PunycodeError::Overflow);
                 }
-                weight *= BASE - t;
-                k += BASE;
+                weight *= params.base - t;
+                k += params.base;
                 byte = match iter.next() {
-                    None => return Err(()), // End of input before the end of this delta
+                    // End of input before the end of this delta
+                    None => return Err(PunycodeError::UnexpectedEnd),
                     Some(byte) => byte,
                 };
             }
 
-            bias = adapt(i - previous_i, length + 1, previous_i == 0);
-            if i / (length + 1) > u32::MAX - code_point {
-                return Err(()); // Overflow
+            bias = params.adapt(i - previous_i, length + 1, previous_i == 0);
+            if i / (length + 1) > U32_MAX - code_point {
+                return Err(PunycodeError::Overflow);
             }
 
             // i was supposed to wrap around from length+1 to 0,
@@ -142,45 +365,86 @@ impl Decoder {
             i %= length + 1;
             let c = match char::from_u32(code_point) {
                 Some(c) => c,
-                None => return Err(()),
+                None => return Err(PunycodeError::InvalidCodePoint(code_point)),
             };
-
-            // Move earlier insertions farther out in the string
-            for (idx, _) in &mut self.insertions {
-                if *idx >= i as usize {
-                    *idx += 1;
-                }
+            if length as usize >= max_code_points {
+                return Err(PunycodeError::TooLong);
             }
+
             self.insertions.push((i as usize, c));
             length += 1;
             i += 1;
         }
 
+        // Each insertion index is relative to the string at the time of insertion.
+        // Walking backwards, the final position of an insertion is the slot
+        // that many places into those not taken by later insertions.
+        if !self.insertions.is_empty() {
+            self.free_slots.reset(base_len + self.insertions.len());
+            for (idx, _) in self.insertions.iter_mut().rev() {
+                *idx = self.free_slots.take_nth(*idx);
+            }
+        }
         self.insertions.sort_by_key(|(i, _)| *i);
-        Ok(Decode {
-            base: base.chars(),
-            insertions: &self.insertions,
-            inserted: 0,
-            position: 0,
-            len: base_len + self.insertions.len(),
-        })
+        Ok(base)
     }
 }
 
-pub(crate) struct Decode<'a> {
-    base: std::str::Chars<'a>,
-    pub(crate) insertions: &'a [(usize, char)],
+/// A binary indexed tree of free slots, for finding the nth one in O(log n).
+#[derive(Default)]
+struct FenwickTree {
+    /// `tree[i - 1]` is the number of free slots in `(i - lowest_bit(i))..i`.
+    tree: Vec<usize>,
+}
+
+impl FenwickTree {
+    /// Mark `len` slots as free.
+    fn reset(&mut self, len: usize) {
+        self.tree.clear();
+        self.tree.extend((1..=len).map(|i| i & i.wrapping_neg()));
+    }
+
+    /// Find the free slot with `n` free slots before it, mark it as taken, and return it.
+    fn take_nth(&mut self, n: usize) -> usize {
+        let len = self.tree.len();
+        let (mut position, mut remaining) = (0, n);
+        let mut step = len.next_power_of_two();
+        while step > 0 {
+            if position + step <= len && self.tree[position + step - 1] <= remaining {
+                position += step;
+                remaining -= self.tree[position - 1];
+            }
+            step >>= 1;
+        }
+        let slot = position;
+        let mut i = slot + 1;
+        while i <= len {
+            self.tree[i - 1] -= 1;
+            i += i & i.wrapping_neg();
+        }
+        slot
+    }
+}
+
+/// Interleaves basic code points with sorted insertions.
+struct Merge<'a> {
+    base: core::str::Chars<'a>,
     inserted: usize,
     position: usize,
-    len: usize,
 }
 
-impl<'a> Iterator for Decode<'a> {
-    type Item = char;
+impl<'a> Merge<'a> {
+    fn new(base: &'a str) -> Self {
+        Merge {
+            base: base.chars(),
+            inserted: 0,
+            position: 0,
+        }
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
+    fn next(&mut self, insertions: &[(usize, char)]) -> Option<char> {
         loop {
-            match self.insertions.get(self.inserted) {
+            match insertions.get(self.inserted) {
                 Some((pos, c)) if *pos == self.position => {
                     self.inserted += 1;
                     self.position += 1;
@@ -191,21 +455,98 @@ impl<'a> Iterator for Decode<'a> {
             if let Some(c) = self.base.next() {
                 self.position += 1;
                 return Some(c);
-            } else if self.inserted >= self.insertions.len() {
+            } else if self.inserted >= insertions.len() {
                 return None;
             }
         }
     }
+}
+
+pub(crate) struct Decode<'a> {
+    merge: Merge<'a>,
+    pub(crate) insertions: &'a [(usize, char)],
+    len: usize,
+}
+
+impl<'a> Iterator for Decode<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.merge.next(self.insertions)
+    }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let len = self.len - self.position;
+        let len = self.len - self.merge.position;
         (len, Some(len))
     }
 }
 
 impl<'a> ExactSizeIterator for Decode<'a> {
     fn len(&self) -> usize {
-        self.len - self.position
+        self.len - self.merge.position
+    }
+}
+
+/// Convert Punycode to Unicode, as an iterator.
+///
+/// The input is only decoded when the first item is requested.
+/// Basic code points are then yielded straight from `input`, without being copied.
+///
+/// This is not a streaming decoder: a later Punycode delta can insert a code point
+/// before any earlier one, so every delta is decoded before the first item is yielded,
+/// and the non-basic code points are buffered along with their positions.
+/// For input without basic code points, such as most CJK labels,
+/// this uses as much memory as `decode`.
+///
+/// On malformed input or overflow, a single error is yielded and iteration ends.
+pub fn decode_iter(input: &str) -> DecodeIter<'_> {
+    DecodeIter {
+        input: Some(input),
+        decoder: Decoder::default(),
+        merge: Merge::new(""),
+        len: 0,
+    }
+}
+
+/// The return type of `decode_iter`.
+pub struct DecodeIter<'a> {
+    input: Option<&'a str>,
+    decoder: Decoder,
+    merge: Merge<'a>,
+    len: usize,
+}
+
+impl<'a> Iterator for DecodeIter<'a> {
+    type Item = Result<char, PunycodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(input) = self.input.take() {
+            match self
+                .decoder
+                .decode_insertions(&Punycode::DEFAULT, input, USIZE_MAX)
+            {
+                Ok(base) => {
+                    self.merge = Merge::new(base);
+                    self.len = base.len() + self.decoder.insertions.len();
+                }
+                Err(error) => {
+                    self.decoder.insertions.clear();
+                    return Some(Err(error));
+                }
+            }
+        }
+        self.merge.next(&self.decoder.insertions).map(Ok)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.input {
+            // Every code point or error takes at least one byte of input.
+            Some(input) => (0, Some(input.len())),
+            None => {
+                let len = self.len - self.merge.position;
+                (len, Some(len))
+            }
+        }
     }
 }
 
@@ -218,10 +559,29 @@ pub fn encode_str(input: &str) -> Option<String> {
     encode_into(input.chars(), &mut buf).ok().map(|()| buf)
 }
 
+/// Return the length in bytes of the Punycode encoding of `input`, without building it.
+///
+/// This is `encode_str(input).map(|s| s.len())` without allocating.
+/// Return None on overflow.
+pub fn encoded_len(input: &str) -> Option<usize> {
+    struct Counter(usize);
+
+    impl fmt::Write for Counter {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0 += s.len();
+            Ok(())
+        }
+    }
+
+    let mut counter = Counter(0);
+    encode_into(input.chars(), &mut counter).ok()?;
+    Some(counter.0)
+}
+
 /// Convert Unicode to Punycode.
 ///
 /// Return None on overflow, which can only happen on inputs that would take more than
-/// 63 encoded bytes, the DNS limit on domain name labels.
+/// `PUNYCODE_MAX_LABEL_BYTES` encoded bytes, the DNS limit on domain name labels.
 pub fn encode(input: &[char]) -> Option<String> {
     let mut buf = String::with_capacity(input.len());
     encode_into(input.iter().copied(), &mut buf)
@@ -229,26 +589,123 @@ pub fn encode(input: &[char]) -> Option<String> {
         .map(|()| buf)
 }
 
-pub(crate) fn encode_into<I>(input: I, output: &mut String) -> Result<(), ()>
+/// Convert Unicode to Punycode, checking the DNS limit on domain name labels.
+///
+/// Fail with `EncodeError::TooLong` if the encoding, with the `xn--` prefix of IDNA labels,
+/// would take more than `PUNYCODE_MAX_LABEL_BYTES` bytes,
+/// and with `EncodeError::Overflow` if it can't be computed at all.
+pub fn encode_checked(input: &[char]) -> Result<String, EncodeError> {
+    let mut buf = String::with_capacity(input.len());
+    encode_into(input.iter().copied(), &mut buf)?;
+    if "xn--".len() + buf.len() > PUNYCODE_MAX_LABEL_BYTES {
+        return Err(EncodeError::TooLong);
+    }
+    Ok(buf)
+}
+
+/// Convert Unicode to Punycode, appending the result to `output`.
+///
+/// The input iterator is traversed several times, so it should be cheap to clone.
+/// Nothing is allocated, which makes this suitable for serializing many labels
+/// into one buffer.
+///
+/// On error, `output` may contain a partial encoding.
+pub fn encode_into<I, W>(input: I, output: &mut W) -> Result<(), EncodeError>
+where
+    I: Iterator<Item = char> + Clone,
+    W: fmt::Write + ?Sized,
+{
+    encode_into_with(&Punycode::DEFAULT, input, output)
+}
+
+/// A Punycode encoder for code points produced one at a time.
+///
+/// Bootstring needs the whole set of code points of a label before writing any output,
+/// and traverses them several times, so the pushed code points are buffered internally
+/// until `finish`. The buffer is kept, which makes the same `Encoder` cheap to reuse.
+///
+/// ```
+/// use idna::punycode::Encoder;
+///
+/// let mut encoder = Encoder::new();
+/// for c in "bücher".chars() {
+///     encoder.push(c);
+/// }
+/// assert_eq!(encoder.finish().unwrap(), "bcher-kva");
+///
+/// encoder.extend("münchen".chars());
+/// assert_eq!(encoder.finish().unwrap(), "mnchen-3ya");
+/// ```
+#[derive(Default, Clone, Debug)]
+pub struct Encoder {
+    input: Vec<char>,
+}
+
+impl Encoder {
+    /// Create an encoder with an empty buffer, which is reused across calls to `finish`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a code point at the end of the label to encode.
+    #[inline]
+    pub fn push(&mut self, c: char) {
+        self.input.push(c)
+    }
+
+    /// Encode the code points pushed since the last call, and start a new label.
+    pub fn finish(&mut self) -> Result<String, EncodeError> {
+        let mut buf = String::with_capacity(self.input.len());
+        let result = encode_into(self.input.iter().copied(), &mut buf);
+        self.input.clear();
+        result.map(|()| buf)
+    }
+}
+
+impl Extend<char> for Encoder {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        self.input.extend(iter)
+    }
+}
+
+/// Convert Unicode to a Bootstring encoding with the given parameters.
+///
+/// Return None on invalid parameters or overflow.
+pub fn encode_with(params: &BootstringParams, input: &[char]) -> Option<String> {
+    if !params.is_valid() {
+        return None;
+    }
+    let mut buf = String::with_capacity(input.len());
+    encode_into_with(params, input.iter().copied(), &mut buf)
+        .ok()
+        .map(|()| buf)
+}
+
+fn encode_into_with<I, W>(
+    params: &BootstringParams,
+    input: I,
+    output: &mut W,
+) -> Result<(), EncodeError>
 where
     I: Iterator<Item = char> + Clone,
+    W: fmt::Write + ?Sized,
 {
     // Handle "basic" (ASCII) code points. They are encoded as-is.
     let (mut input_length, mut basic_length) = (0, 0);
     for c in input.clone() {
         input_length += 1;
-        if c.is_ascii() {
-            output.push(c);
+        if (c as u32) < params.initial_n {
+            output.write_char(c)?;
             basic_length += 1;
         }
     }
 
     if basic_length > 0 {
-        output.push('-')
+        output.write_char(DELIMITER)?;
     }
-    let mut code_point = INITIAL_N;
+    let mut code_point = params.initial_n;
     let mut delta = 0;
-    let mut bias = INITIAL_BIAS;
+    let mut bias = params.initial_bias;
     let mut processed = basic_length;
     while processed < input_length {
         // All code points < code_point have been handled already.
@@ -259,8 +716,8 @@ where
             .filter(|&c| c >= code_point)
             .min()
             .unwrap();
-        if min_code_point - code_point > (u32::MAX - delta) / (processed + 1) {
-            return Err(()); // Overflow
+        if min_code_point - code_point > (U32_MAX - delta) / (processed + 1) {
+            return Err(EncodeError::Overflow);
         }
         // Increase delta to advance the decoder’s <code_point,i> state to <min_code_point,0>
         delta += (min_code_point - code_point) * (processed + 1);
@@ -270,31 +727,25 @@ where
             if c < code_point {
                 delta += 1;
                 if delta == 0 {
-                    return Err(()); // Overflow
+                    return Err(EncodeError::Overflow);
                 }
             }
             if c == code_point {
                 // Represent delta as a generalized variable-length integer:
                 let mut q = delta;
-                let mut k = BASE;
+                let mut k = params.base;
                 loop {
-                    let t = if k <= bias {
-                        T_MIN
-                    } else if k >= bias + T_MAX {
-                        T_MAX
-                    } else {
-                        k - bias
-                    };
+                    let t = params.threshold(k, bias);
                     if q < t {
                         break;
                     }
-                    let value = t + ((q - t) % (BASE - t));
-                    output.push(value_to_digit(value));
-                    q = (q - t) / (BASE - t);
-                    k += BASE;
+                    let value = t + ((q - t) % (params.base - t));
+                    output.write_char(value_to_digit(value)?)?;
+                    q = (q - t) / (params.base - t);
+                    k += params.base;
                 }
-                output.push(value_to_digit(q));
-                bias = adapt(delta, processed + 1, processed == basic_length);
+                output.write_char(value_to_digit(q)?)?;
+                bias = params.adapt(delta, processed + 1, processed == basic_length);
                 delta = 0;
                 processed += 1;
             }
@@ -305,11 +756,87 @@ where
     Ok(())
 }
 
+/// Errors returned by `decode_into`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PunycodeError {
+    /// An arithmetic overflow, which can only happen on inputs that take more than
+    /// `PUNYCODE_MAX_LABEL_BYTES` encoded bytes, the DNS limit on domain name labels.
+    Overflow,
+    /// A byte that is not a base-36 digit (`a-z`, `A-Z` or `0-9`) after the last delimiter.
+    InvalidDigit(u8),
+    /// The input ended in the middle of a variable-length integer.
+    UnexpectedEnd,
+    /// A decoded value that is not a Unicode scalar value,
+    /// or a non-ASCII code point before the last delimiter.
+    InvalidCodePoint(u32),
+    /// The output would be longer than the limit given to `decode_bounded`.
+    TooLong,
+}
+
+impl fmt::Display for PunycodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            PunycodeError::Overflow => f.write_str("overflow while decoding punycode"),
+            PunycodeError::InvalidDigit(byte) => {
+                write!(f, "invalid punycode digit {:?}", char::from(byte))
+            }
+            PunycodeError::UnexpectedEnd => f.write_str("unexpected end of punycode input"),
+            PunycodeError::TooLong => f.write_str("punycode decodes to too many code points"),
+            PunycodeError::InvalidCodePoint(code_point) => {
+                write!(f, "invalid code point U+{:04X} in punycode", code_point)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl StdError for PunycodeError {}
+
+/// Errors returned by `encode_into`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EncodeError {
+    /// An arithmetic overflow, which can only happen on inputs that would take more than
+    /// `PUNYCODE_MAX_LABEL_BYTES` encoded bytes, the DNS limit on domain name labels.
+    Overflow,
+    /// The encoded label would be longer than `PUNYCODE_MAX_LABEL_BYTES`.
+    /// Only returned by `encode_checked`.
+    TooLong,
+    /// The output writer returned an error.
+    Write(fmt::Error),
+    /// A digit value out of the `0..36` range, which `BootstringParams::is_valid` rules out.
+    /// This is never returned, but reported instead of panicking if it ever happened.
+    InvalidDigit(u32),
+}
+
+impl From<fmt::Error> for EncodeError {
+    fn from(error: fmt::Error) -> Self {
+        EncodeError::Write(error)
+    }
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            EncodeError::Overflow => f.write_str("overflow while encoding punycode"),
+            EncodeError::TooLong => f.write_str("punycode label too long for DNS"),
+            EncodeError::Write(_) => f.write_str("error writing punycode output"),
+            EncodeError::InvalidDigit(value) => {
+                write!(f, "invalid digit value {} while encoding punycode", value)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl StdError for EncodeError {}
+
+/// Digit values are less than `base`, which is at most 36 with valid parameters.
+/// Out of range values are reported as `EncodeError::InvalidDigit` rather than panicking.
 #[inline]
-fn value_to_digit(value: u32) -> char {
+fn value_to_digit(value: u32) -> Result<char, EncodeError> {
     match value {
-        0..=25 => (value as u8 + b'a') as char,       // a..z
-        26..=35 => (value as u8 - 26 + b'0') as char, // 0..9
-        _ => panic!(),
+        0..=25 => Ok((value as u8 + b'a') as char),       // a..z
+        26..=35 => Ok((value as u8 - 26 + b'0') as char), // 0..9
+        _ => Err(EncodeError::InvalidDigit(value)),
     }
 }
@@ -0,0 +1,1348 @@
+// Copyright 2013-2020 The rust-url developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Generated by make_script_table.py
+
+const LATIN: ScriptSet = ScriptSet([0x0, 0x1000, 0x0]);
+const LATIN_COMPANIONS: ScriptSet = ScriptSet([0x40cf40a020004828, 0x10010400008c9, 0x72200100]);
+
+static SCRIPT_SETS: &[ScriptSet] = &[
+    ScriptSet([0x0, 0x0, 0x100000000000]),
+    ScriptSet([0x0, 0x0, 0x10000000000]),
+    ScriptSet([0x0, 0x0, 0x1000000000]),
+    ScriptSet([0x0, 0x0, 0x100000000]),
+    ScriptSet([0x0, 0x0, 0x10000000]),
+    ScriptSet([0x0, 0x0, 0x1000000]),
+    ScriptSet([0x0, 0x0, 0x100000]),
+    ScriptSet([0x0, 0x0, 0x10000]),
+    ScriptSet([0x0, 0x0, 0x1000]),
+    ScriptSet([0x0, 0x0, 0x100]),
+    ScriptSet([0x0, 0x0, 0x10]),
+    ScriptSet([0x0, 0x0, 0x1]),
+    ScriptSet([0x0, 0x0, 0x200000000000]),
+    ScriptSet([0x0, 0x0, 0x20000000000]),
+    ScriptSet([0x0, 0x0, 0x2000000000]),
+    ScriptSet([0x0, 0x0, 0x200000000]),
+    ScriptSet([0x0, 0x0, 0x20000000]),
+    ScriptSet([0x0, 0x0, 0x2000000]),
+    ScriptSet([0x0, 0x0, 0x200000]),
+    ScriptSet([0x0, 0x0, 0x20000]),
+    ScriptSet([0x0, 0x0, 0x2000]),
+    ScriptSet([0x0, 0x0, 0x200]),
+    ScriptSet([0x0, 0x0, 0x20]),
+    ScriptSet([0x0, 0x0, 0x2]),
+    ScriptSet([0x0, 0x0, 0x400000000000]),
+    ScriptSet([0x0, 0x0, 0x40000000000]),
+    ScriptSet([0x0, 0x0, 0x4000000000]),
+    ScriptSet([0x0, 0x0, 0x400000000]),
+    ScriptSet([0x0, 0x0, 0x40000000]),
+    ScriptSet([0x0, 0x0, 0x4000000]),
+    ScriptSet([0x0, 0x0, 0x400000]),
+    ScriptSet([0x0, 0x0, 0x40000]),
+    ScriptSet([0x0, 0x0, 0x4000]),
+    ScriptSet([0x0, 0x0, 0x400]),
+    ScriptSet([0x0, 0x0, 0x40]),
+    ScriptSet([0x0, 0x0, 0x4]),
+    ScriptSet([0x0, 0x0, 0x80000000000]),
+    ScriptSet([0x0, 0x0, 0x8000000000]),
+    ScriptSet([0x0, 0x0, 0x800000000]),
+    ScriptSet([0x0, 0x0, 0x80000000]),
+    ScriptSet([0x0, 0x0, 0x8000000]),
+    ScriptSet([0x0, 0x0, 0x800000]),
+    ScriptSet([0x0, 0x0, 0x80000]),
+    ScriptSet([0x0, 0x0, 0x8000]),
+    ScriptSet([0x0, 0x0, 0x800]),
+    ScriptSet([0x0, 0x0, 0x80]),
+    ScriptSet([0x0, 0x0, 0x8]),
+    ScriptSet([0x0, 0x10, 0x0]),
+    ScriptSet([0x0, 0x100, 0x0]),
+    ScriptSet([0x0, 0x1000, 0x0]),
+    ScriptSet([0x0, 0x1000, 0x10000]),
+    ScriptSet([0x0, 0x1000, 0x14000]),
+    ScriptSet([0x0, 0x1000, 0x20000000]),
+    ScriptSet([0x0, 0x1000, 0x4000000]),
+    ScriptSet([0x0, 0x1000, 0x4000]),
+    ScriptSet([0x0, 0x10000, 0x0]),
+    ScriptSet([0x0, 0x100000, 0x0]),
+    ScriptSet([0x0, 0x1000000, 0x0]),
+    ScriptSet([0x0, 0x10000000, 0x0]),
+    ScriptSet([0x0, 0x100000000, 0x0]),
+    ScriptSet([0x0, 0x1000000000, 0x0]),
+    ScriptSet([0x0, 0x10000000000, 0x0]),
+    ScriptSet([0x0, 0x100000000000, 0x0]),
+    ScriptSet([0x0, 0x1000000000000, 0x0]),
+    ScriptSet([0x0, 0x10000000000000, 0x0]),
+    ScriptSet([0x0, 0x100000000000000, 0x0]),
+    ScriptSet([0x0, 0x1000000000000000, 0x0]),
+    ScriptSet([0x0, 0x2, 0x0]),
+    ScriptSet([0x0, 0x20, 0x0]),
+    ScriptSet([0x0, 0x200, 0x0]),
+    ScriptSet([0x0, 0x2000, 0x0]),
+    ScriptSet([0x0, 0x20000, 0x0]),
+    ScriptSet([0x0, 0x200000, 0x0]),
+    ScriptSet([0x0, 0x2000000, 0x0]),
+    ScriptSet([0x0, 0x20000000, 0x0]),
+    ScriptSet([0x0, 0x200000000, 0x0]),
+    ScriptSet([0x0, 0x2000000000, 0x0]),
+    ScriptSet([0x0, 0x20000000000, 0x0]),
+    ScriptSet([0x0, 0x200000000000, 0x0]),
+    ScriptSet([0x0, 0x2000000000000, 0x0]),
+    ScriptSet([0x0, 0x20000000000000, 0x0]),
+    ScriptSet([0x0, 0x200000000000000, 0x0]),
+    ScriptSet([0x0, 0x2000000000000000, 0x0]),
+    ScriptSet([0x0, 0x2000000001000, 0x0]),
+    ScriptSet([0x0, 0x21000, 0x0]),
+    ScriptSet([0x0, 0x4, 0x0]),
+    ScriptSet([0x0, 0x40, 0x0]),
+    ScriptSet([0x0, 0x400, 0x0]),
+    ScriptSet([0x0, 0x4000, 0x0]),
+    ScriptSet([0x0, 0x40000, 0x0]),
+    ScriptSet([0x0, 0x400000, 0x0]),
+    ScriptSet([0x0, 0x4000000, 0x0]),
+    ScriptSet([0x0, 0x40000000, 0x0]),
+    ScriptSet([0x0, 0x400000000, 0x0]),
+    ScriptSet([0x0, 0x4000000000, 0x0]),
+    ScriptSet([0x0, 0x40000000000, 0x0]),
+    ScriptSet([0x0, 0x400000000000, 0x0]),
+    ScriptSet([0x0, 0x4000000000000, 0x0]),
+    ScriptSet([0x0, 0x40000000000000, 0x0]),
+    ScriptSet([0x0, 0x400000000000000, 0x0]),
+    ScriptSet([0x0, 0x4000000000000000, 0x0]),
+    ScriptSet([0x0, 0x4000000040, 0x1000000000]),
+    ScriptSet([0x0, 0x8, 0x0]),
+    ScriptSet([0x0, 0x800, 0x0]),
+    ScriptSet([0x0, 0x8000, 0x0]),
+    ScriptSet([0x0, 0x80000, 0x0]),
+    ScriptSet([0x0, 0x800000, 0x0]),
+    ScriptSet([0x0, 0x8000000, 0x0]),
+    ScriptSet([0x0, 0x80000000, 0x0]),
+    ScriptSet([0x0, 0x800000000, 0x0]),
+    ScriptSet([0x0, 0x8000000000, 0x0]),
+    ScriptSet([0x0, 0x80000000000, 0x0]),
+    ScriptSet([0x0, 0x800000000000, 0x0]),
+    ScriptSet([0x0, 0x8000000000000, 0x0]),
+    ScriptSet([0x0, 0x80000000000000, 0x0]),
+    ScriptSet([0x0, 0x800000000000000, 0x0]),
+    ScriptSet([0x0, 0x8000000000000000, 0x0]),
+    ScriptSet([0x0, 0x8000000800000, 0x0]),
+    ScriptSet([0x0, 0x80000100000000, 0x0]),
+    ScriptSet([0x0, 0x80000100001000, 0x0]),
+    ScriptSet([0x1, 0x0, 0x0]),
+    ScriptSet([0x10, 0x0, 0x0]),
+    ScriptSet([0x100, 0x0, 0x0]),
+    ScriptSet([0x1000, 0x0, 0x0]),
+    ScriptSet([0x10000, 0x0, 0x0]),
+    ScriptSet([0x100000, 0x0, 0x0]),
+    ScriptSet([0x1000000, 0x0, 0x0]),
+    ScriptSet([0x10000000, 0x0, 0x0]),
+    ScriptSet([0x10000000, 0x1000, 0x0]),
+    ScriptSet([0x10000000, 0x1000, 0x10000]),
+    ScriptSet([0x10000000, 0x1000, 0x200000000]),
+    ScriptSet([0x10000000, 0x40000000000000, 0x0]),
+    ScriptSet([0x100000000, 0x0, 0x0]),
+    ScriptSet([0x1000000000, 0x0, 0x0]),
+    ScriptSet([0x10000000000, 0x0, 0x0]),
+    ScriptSet([0x10000000000, 0x1000, 0x20014000]),
+    ScriptSet([0x100000000000, 0x0, 0x0]),
+    ScriptSet([0x100000000000, 0x0, 0x200000]),
+    ScriptSet([0x1000000000000, 0x0, 0x0]),
+    ScriptSet([0x1000000000000, 0x800000000, 0x0]),
+    ScriptSet([0x10000000000000, 0x0, 0x0]),
+    ScriptSet([0x100000000000000, 0x0, 0x0]),
+    ScriptSet([0x1000000000000000, 0x0, 0x0]),
+    ScriptSet([0x10000000040000, 0x0, 0x8020000]),
+    ScriptSet([0x100020000000, 0x0, 0x0]),
+    ScriptSet([0x100020000000, 0x1000, 0x0]),
+    ScriptSet([0x100020000000, 0x40, 0x0]),
+    ScriptSet([0x100020000000, 0x40, 0x1000000000]),
+    ScriptSet([0x100020000800, 0x1004040000040, 0x1082000100]),
+    ScriptSet([0x100020000800, 0x40, 0x0]),
+    ScriptSet([0x10010000000, 0x0, 0x0]),
+    ScriptSet([0x10800000, 0x1000, 0x4000000]),
+    ScriptSet([0x10800000, 0x2000000001000, 0x0]),
+    ScriptSet([0x14000a0000000, 0x4080100250, 0x1080040090]),
+    ScriptSet([0x14000a0000000, 0x40c0100250, 0x1080040090]),
+    ScriptSet([0x14000a0000000, 0x80100210, 0x80040080]),
+    ScriptSet([0x14000a0000000, 0x80100210, 0x80040090]),
+    ScriptSet([0x1500020000800, 0x1020040001040, 0x82200000]),
+    ScriptSet([0x1500020000800, 0x1024040001040, 0x82200010]),
+    ScriptSet([0x15600a0000800, 0x1404040100040, 0x82248180]),
+    ScriptSet([0x18000000000, 0x1000, 0x0]),
+    ScriptSet([0x18000000020, 0x0, 0x0]),
+    ScriptSet([0x1d600a0000800, 0x1404040104040, 0x82248180]),
+    ScriptSet([0x2, 0x0, 0x0]),
+    ScriptSet([0x2, 0x1000, 0x200000000]),
+    ScriptSet([0x20, 0x0, 0x0]),
+    ScriptSet([0x200, 0x0, 0x0]),
+    ScriptSet([0x2000, 0x0, 0x0]),
+    ScriptSet([0x20000, 0x0, 0x0]),
+    ScriptSet([0x200000, 0x0, 0x0]),
+    ScriptSet([0x2000000, 0x0, 0x0]),
+    ScriptSet([0x2000000, 0x1000, 0x0]),
+    ScriptSet([0x20000000, 0x0, 0x0]),
+    ScriptSet([0x20000000, 0x0, 0x10]),
+    ScriptSet([0x20000000, 0x0, 0x200000]),
+    ScriptSet([0x20000000, 0x1000040000040, 0x2200000]),
+    ScriptSet([0x20000000, 0x20000000000, 0x0]),
+    ScriptSet([0x20000000, 0x20000000000, 0x10]),
+    ScriptSet([0x20000000, 0x20000000000, 0x80000000]),
+    ScriptSet([0x20000000, 0x24000000000, 0x0]),
+    ScriptSet([0x200000000, 0x0, 0x0]),
+    ScriptSet([0x200000000, 0x1000, 0x10000]),
+    ScriptSet([0x2000000000, 0x0, 0x0]),
+    ScriptSet([0x2000000000, 0x1000, 0x0]),
+    ScriptSet([0x20000000000, 0x0, 0x0]),
+    ScriptSet([0x200000000000, 0x0, 0x0]),
+    ScriptSet([0x200000000000, 0x40000000001000, 0x200000000]),
+    ScriptSet([0x20000000000000, 0x0, 0x0]),
+    ScriptSet([0x200000000000000, 0x0, 0x0]),
+    ScriptSet([0x2000000000000000, 0x0, 0x0]),
+    ScriptSet([0x2000000000020000, 0x0, 0x0]),
+    ScriptSet([0x2000000004000, 0x0, 0x0]),
+    ScriptSet([0x2000000004000, 0x1000, 0x0]),
+    ScriptSet([0x2000008, 0x0, 0x0]),
+    ScriptSet([0x200002000000, 0x0, 0x0]),
+    ScriptSet([0x20000800, 0x0, 0x0]),
+    ScriptSet([0x20000800, 0x0, 0x1000000000]),
+    ScriptSet([0x20000800, 0x0, 0x10]),
+    ScriptSet([0x20000800, 0x0, 0x2000000]),
+    ScriptSet([0x20000800, 0x20000000000, 0x10]),
+    ScriptSet([0x20000800, 0x20000000000, 0x2000000]),
+    ScriptSet([0x20000800, 0x20000000000, 0x82000000]),
+    ScriptSet([0x200010000000, 0x40000000001000, 0x4000000]),
+    ScriptSet([0x200010800000, 0x2000000001000, 0x200084000]),
+    ScriptSet([0x200012800000, 0x40000000001000, 0x84000]),
+    ScriptSet([0x200800000, 0x1000, 0x10000]),
+    ScriptSet([0x280012800002, 0x2000000001000, 0x204010000]),
+    ScriptSet([0x30000800, 0x21000, 0x820000000]),
+    ScriptSet([0x4, 0x0, 0x0]),
+    ScriptSet([0x40, 0x0, 0x0]),
+    ScriptSet([0x40, 0x800000000000, 0x0]),
+    ScriptSet([0x400, 0x0, 0x0]),
+    ScriptSet([0x40000, 0x0, 0x0]),
+    ScriptSet([0x400000, 0x0, 0x0]),
+    ScriptSet([0x4000000, 0x0, 0x0]),
+    ScriptSet([0x40000000, 0x0, 0x0]),
+    ScriptSet([0x400000000, 0x0, 0x0]),
+    ScriptSet([0x4000000000, 0x0, 0x0]),
+    ScriptSet([0x40000000000, 0x0, 0x0]),
+    ScriptSet([0x400000000000, 0x0, 0x0]),
+    ScriptSet([0x400000000000, 0x10, 0x0]),
+    ScriptSet([0x4000000000000, 0x80, 0x0]),
+    ScriptSet([0x40000000000000, 0x0, 0x0]),
+    ScriptSet([0x400000000000000, 0x0, 0x0]),
+    ScriptSet([0x4000000000000000, 0x1, 0x0]),
+    ScriptSet([0x4000000008, 0x2000040000000000, 0x100010010000]),
+    ScriptSet([0x4000000009, 0x2000040000000000, 0x100010010000]),
+    ScriptSet([0x4000000200800000, 0x1001, 0x4010000]),
+    ScriptSet([0x40000202000000, 0x40000000001000, 0x204090000]),
+    ScriptSet([0x4000090802000000, 0x1001, 0x0]),
+    ScriptSet([0x400a000000000000, 0x1080, 0x0]),
+    ScriptSet([0x400a000000000000, 0x80, 0x0]),
+    ScriptSet([0x400a000000000000, 0x80, 0x400000]),
+    ScriptSet([0x400a000000004000, 0x80, 0x0]),
+    ScriptSet([0x400a2b8a02200040, 0x40000000181080, 0x8]),
+    ScriptSet([0x40280210000020, 0x40000000001000, 0x4090000]),
+    ScriptSet([0x4080000000000000, 0x0, 0x0]),
+    ScriptSet([0x4080000000000000, 0x1, 0x0]),
+    ScriptSet([0x408a000000000000, 0x81, 0x0]),
+    ScriptSet([0x408e000000004000, 0x100000081, 0x200000000000]),
+    ScriptSet([0x408e000000004000, 0x100000081, 0x200040000000]),
+    ScriptSet([0x408e000000004000, 0x100020081, 0x200040000000]),
+    ScriptSet([0x408e000000004000, 0x80000100000081, 0x200000000000]),
+    ScriptSet([0x408e000000004000, 0x81, 0x0]),
+    ScriptSet([0x408e000000004000, 0x81, 0x200000000000]),
+    ScriptSet([0x8, 0x0, 0x0]),
+    ScriptSet([0x8, 0x0, 0x10000000]),
+    ScriptSet([0x8, 0x0, 0x10000]),
+    ScriptSet([0x8, 0x0, 0x100010000000]),
+    ScriptSet([0x8, 0x0, 0x10010000]),
+    ScriptSet([0x8, 0x2000000000000000, 0x0]),
+    ScriptSet([0x8, 0x40000000000, 0x0]),
+    ScriptSet([0x80, 0x0, 0x0]),
+    ScriptSet([0x800, 0x0, 0x0]),
+    ScriptSet([0x8000, 0x0, 0x0]),
+    ScriptSet([0x80000, 0x0, 0x0]),
+    ScriptSet([0x80000, 0x1000000000, 0x80000]),
+    ScriptSet([0x800000, 0x0, 0x0]),
+    ScriptSet([0x800000, 0x1000, 0x10000]),
+    ScriptSet([0x800000, 0x1000, 0x80000]),
+    ScriptSet([0x8000000, 0x0, 0x0]),
+    ScriptSet([0x8000000, 0x10000, 0x0]),
+    ScriptSet([0x8000000, 0x18000, 0x0]),
+    ScriptSet([0x80000000, 0x0, 0x0]),
+    ScriptSet([0x800000000, 0x0, 0x0]),
+    ScriptSet([0x8000000000, 0x0, 0x0]),
+    ScriptSet([0x80000000000, 0x0, 0x0]),
+    ScriptSet([0x800000000000, 0x0, 0x0]),
+    ScriptSet([0x800000000000000, 0x0, 0x0]),
+    ScriptSet([0x8000000000000000, 0x0, 0x0]),
+    ScriptSet([0x8000000000000000, 0x1000001000, 0x0]),
+    ScriptSet([0x800000000000009, 0x0, 0x0]),
+    ScriptSet([0x80000800002, 0x1000, 0x20014000]),
+    ScriptSet([0x800008000200040, 0x8000000000080200, 0x0]),
+    ScriptSet([0x800018000200000, 0x800000040000, 0x0]),
+    ScriptSet([0x800200000200000, 0x10000000, 0x0]),
+    ScriptSet([0x80800, 0x0, 0x8000]),
+    ScriptSet([0x9, 0x0, 0x0]),
+    ScriptSet([0x9, 0x2208000000c00000, 0x10200]),
+    ScriptSet([0xa0000000, 0x100200, 0x0]),
+    ScriptSet([0xc000000, 0x10000, 0x0]),
+];
+
+static SCRIPT_TABLE: &[(char, char, u16)] = &[
+    ('\u{41}', '\u{5a}', 49),
+    ('\u{61}', '\u{7a}', 49),
+    ('\u{aa}', '\u{aa}', 49),
+    ('\u{b7}', '\u{b7}', 234),
+    ('\u{ba}', '\u{ba}', 49),
+    ('\u{c0}', '\u{d6}', 49),
+    ('\u{d8}', '\u{f6}', 49),
+    ('\u{f8}', '\u{2b8}', 49),
+    ('\u{2bc}', '\u{2bc}', 207),
+    ('\u{2c7}', '\u{2c7}', 192),
+    ('\u{2c9}', '\u{2cb}', 192),
+    ('\u{2cd}', '\u{2cd}', 84),
+    ('\u{2d7}', '\u{2d7}', 52),
+    ('\u{2d9}', '\u{2d9}', 192),
+    ('\u{2e0}', '\u{2e4}', 49),
+    ('\u{2ea}', '\u{2eb}', 191),
+    ('\u{300}', '\u{300}', 204),
+    ('\u{301}', '\u{301}', 203),
+    ('\u{302}', '\u{302}', 151),
+    ('\u{303}', '\u{303}', 135),
+    ('\u{304}', '\u{304}', 206),
+    ('\u{305}', '\u{305}', 229),
+    ('\u{306}', '\u{306}', 202),
+    ('\u{307}', '\u{307}', 228),
+    ('\u{308}', '\u{308}', 235),
+    ('\u{309}', '\u{309}', 53),
+    ('\u{30a}', '\u{30a}', 181),
+    ('\u{30b}', '\u{30b}', 152),
+    ('\u{30c}', '\u{30c}', 259),
+    ('\u{30d}', '\u{30d}', 54),
+    ('\u{30e}', '\u{30e}', 183),
+    ('\u{310}', '\u{310}', 54),
+    ('\u{311}', '\u{311}', 130),
+    ('\u{313}', '\u{313}', 186),
+    ('\u{323}', '\u{323}', 227),
+    ('\u{324}', '\u{324}', 205),
+    ('\u{325}', '\u{325}', 50),
+    ('\u{32d}', '\u{32d}', 51),
+    ('\u{32e}', '\u{32e}', 50),
+    ('\u{330}', '\u{330}', 258),
+    ('\u{331}', '\u{331}', 272),
+    ('\u{342}', '\u{342}', 185),
+    ('\u{345}', '\u{345}', 185),
+    ('\u{358}', '\u{358}', 83),
+    ('\u{35e}', '\u{35e}', 164),
+    ('\u{363}', '\u{36f}', 49),
+    ('\u{370}', '\u{373}', 185),
+    ('\u{374}', '\u{375}', 194),
+    ('\u{376}', '\u{377}', 185),
+    ('\u{37a}', '\u{37d}', 185),
+    ('\u{37f}', '\u{37f}', 185),
+    ('\u{384}', '\u{384}', 185),
+    ('\u{386}', '\u{386}', 185),
+    ('\u{388}', '\u{38a}', 185),
+    ('\u{38c}', '\u{38c}', 185),
+    ('\u{38e}', '\u{3a1}', 185),
+    ('\u{3a3}', '\u{3e1}', 185),
+    ('\u{3e2}', '\u{3ef}', 170),
+    ('\u{3f0}', '\u{3ff}', 185),
+    ('\u{400}', '\u{482}', 127),
+    ('\u{483}', '\u{483}', 131),
+    ('\u{484}', '\u{484}', 150),
+    ('\u{485}', '\u{486}', 128),
+    ('\u{487}', '\u{487}', 150),
+    ('\u{488}', '\u{52f}', 127),
+    ('\u{531}', '\u{556}', 165),
+    ('\u{559}', '\u{588}', 165),
+    ('\u{589}', '\u{589}', 161),
+    ('\u{58a}', '\u{58a}', 165),
+    ('\u{58d}', '\u{58f}', 165),
+    ('\u{591}', '\u{5c7}', 222),
+    ('\u{5d0}', '\u{5ea}', 222),
+    ('\u{5ef}', '\u{5f4}', 222),
+    ('\u{600}', '\u{604}', 245),
+    ('\u{606}', '\u{60b}', 245),
+    ('\u{60c}', '\u{60c}', 225),
+    ('\u{60d}', '\u{61a}', 245),
+    ('\u{61b}', '\u{61b}', 225),
+    ('\u{61c}', '\u{61c}', 249),
+    ('\u{61d}', '\u{61e}', 245),
+    ('\u{61f}', '\u{61f}', 226),
+    ('\u{620}', '\u{63f}', 245),
+    ('\u{640}', '\u{640}', 278),
+    ('\u{641}', '\u{64a}', 245),
+    ('\u{64b}', '\u{655}', 247),
+    ('\u{656}', '\u{65f}', 245),
+    ('\u{660}', '\u{669}', 248),
+    ('\u{66a}', '\u{66f}', 245),
+    ('\u{670}', '\u{670}', 247),
+    ('\u{671}', '\u{6d3}', 245),
+    ('\u{6d4}', '\u{6d4}', 250),
+    ('\u{6d5}', '\u{6dc}', 245),
+    ('\u{6de}', '\u{6ff}', 245),
+    ('\u{700}', '\u{70d}', 7),
+    ('\u{70f}', '\u{74a}', 7),
+    ('\u{74d}', '\u{74f}', 7),
+    ('\u{750}', '\u{77f}', 245),
+    ('\u{780}', '\u{7b1}', 4),
+    ('\u{7c0}', '\u{7fa}', 95),
+    ('\u{7fd}', '\u{7ff}', 95),
+    ('\u{800}', '\u{82d}', 116),
+    ('\u{830}', '\u{83e}', 116),
+    ('\u{840}', '\u{85b}', 90),
+    ('\u{85e}', '\u{85e}', 90),
+    ('\u{860}', '\u{86a}', 7),
+    ('\u{870}', '\u{891}', 245),
+    ('\u{897}', '\u{8e1}', 245),
+    ('\u{8e3}', '\u{8ff}', 245),
+    ('\u{900}', '\u{950}', 172),
+    ('\u{951}', '\u{951}', 158),
+    ('\u{952}', '\u{952}', 157),
+    ('\u{955}', '\u{963}', 172),
+    ('\u{964}', '\u{964}', 159),
+    ('\u{965}', '\u{965}', 162),
+    ('\u{966}', '\u{96f}', 279),
+    ('\u{970}', '\u{97f}', 172),
+    ('\u{980}', '\u{983}', 253),
+    ('\u{985}', '\u{98c}', 253),
+    ('\u{98f}', '\u{990}', 253),
+    ('\u{993}', '\u{9a8}', 253),
+    ('\u{9aa}', '\u{9b0}', 253),
+    ('\u{9b2}', '\u{9b2}', 253),
+    ('\u{9b6}', '\u{9b9}', 253),
+    ('\u{9bc}', '\u{9c4}', 253),
+    ('\u{9c7}', '\u{9c8}', 253),
+    ('\u{9cb}', '\u{9ce}', 253),
+    ('\u{9d7}', '\u{9d7}', 253),
+    ('\u{9dc}', '\u{9dd}', 253),
+    ('\u{9df}', '\u{9e3}', 253),
+    ('\u{9e6}', '\u{9ef}', 276),
+    ('\u{9f0}', '\u{9fe}', 253),
+    ('\u{a01}', '\u{a03}', 138),
+    ('\u{a05}', '\u{a0a}', 138),
+    ('\u{a0f}', '\u{a10}', 138),
+    ('\u{a13}', '\u{a28}', 138),
+    ('\u{a2a}', '\u{a30}', 138),
+    ('\u{a32}', '\u{a33}', 138),
+    ('\u{a35}', '\u{a36}', 138),
+    ('\u{a38}', '\u{a39}', 138),
+    ('\u{a3c}', '\u{a3c}', 138),
+    ('\u{a3e}', '\u{a42}', 138),
+    ('\u{a47}', '\u{a48}', 138),
+    ('\u{a4b}', '\u{a4d}', 138),
+    ('\u{a51}', '\u{a51}', 138),
+    ('\u{a59}', '\u{a5c}', 138),
+    ('\u{a5e}', '\u{a5e}', 138),
+    ('\u{a66}', '\u{a6f}', 139),
+    ('\u{a70}', '\u{a76}', 138),
+    ('\u{a81}', '\u{a83}', 219),
+    ('\u{a85}', '\u{a8d}', 219),
+    ('\u{a8f}', '\u{a91}', 219),
+    ('\u{a93}', '\u{aa8}', 219),
+    ('\u{aaa}', '\u{ab0}', 219),
+    ('\u{ab2}', '\u{ab3}', 219),
+    ('\u{ab5}', '\u{ab9}', 219),
+    ('\u{abc}', '\u{ac5}', 219),
+    ('\u{ac7}', '\u{ac9}', 219),
+    ('\u{acb}', '\u{acd}', 219),
+    ('\u{ad0}', '\u{ad0}', 219),
+    ('\u{ae0}', '\u{ae3}', 219),
+    ('\u{ae6}', '\u{aef}', 220),
+    ('\u{af0}', '\u{af1}', 219),
+    ('\u{af9}', '\u{aff}', 219),
+    ('\u{b01}', '\u{b03}', 63),
+    ('\u{b05}', '\u{b0c}', 63),
+    ('\u{b0f}', '\u{b10}', 63),
+    ('\u{b13}', '\u{b28}', 63),
+    ('\u{b2a}', '\u{b30}', 63),
+    ('\u{b32}', '\u{b33}', 63),
+    ('\u{b35}', '\u{b39}', 63),
+    ('\u{b3c}', '\u{b44}', 63),
+    ('\u{b47}', '\u{b48}', 63),
+    ('\u{b4b}', '\u{b4d}', 63),
+    ('\u{b55}', '\u{b57}', 63),
+    ('\u{b5c}', '\u{b5d}', 63),
+    ('\u{b5f}', '\u{b63}', 63),
+    ('\u{b66}', '\u{b77}', 63),
+    ('\u{b82}', '\u{b83}', 18),
+    ('\u{b85}', '\u{b8a}', 18),
+    ('\u{b8e}', '\u{b90}', 18),
+    ('\u{b92}', '\u{b95}', 18),
+    ('\u{b99}', '\u{b9a}', 18),
+    ('\u{b9c}', '\u{b9c}', 18),
+    ('\u{b9e}', '\u{b9f}', 18),
+    ('\u{ba3}', '\u{ba4}', 18),
+    ('\u{ba8}', '\u{baa}', 18),
+    ('\u{bae}', '\u{bb9}', 18),
+    ('\u{bbe}', '\u{bc2}', 18),
+    ('\u{bc6}', '\u{bc8}', 18),
+    ('\u{bca}', '\u{bcd}', 18),
+    ('\u{bd0}', '\u{bd0}', 18),
+    ('\u{bd7}', '\u{bd7}', 18),
+    ('\u{be6}', '\u{bf3}', 137),
+    ('\u{bf4}', '\u{bfa}', 18),
+    ('\u{c00}', '\u{c0c}', 17),
+    ('\u{c0e}', '\u{c10}', 17),
+    ('\u{c12}', '\u{c28}', 17),
+    ('\u{c2a}', '\u{c39}', 17),
+    ('\u{c3c}', '\u{c44}', 17),
+    ('\u{c46}', '\u{c48}', 17),
+    ('\u{c4a}', '\u{c4d}', 17),
+    ('\u{c55}', '\u{c56}', 17),
+    ('\u{c58}', '\u{c5a}', 17),
+    ('\u{c5c}', '\u{c5d}', 17),
+    ('\u{c60}', '\u{c63}', 17),
+    ('\u{c66}', '\u{c6f}', 17),
+    ('\u{c77}', '\u{c7f}', 17),
+    ('\u{c80}', '\u{c8c}', 86),
+    ('\u{c8e}', '\u{c90}', 86),
+    ('\u{c92}', '\u{ca8}', 86),
+    ('\u{caa}', '\u{cb3}', 86),
+    ('\u{cb5}', '\u{cb9}', 86),
+    ('\u{cbc}', '\u{cc4}', 86),
+    ('\u{cc6}', '\u{cc8}', 86),
+    ('\u{cca}', '\u{ccd}', 86),
+    ('\u{cd5}', '\u{cd6}', 86),
+    ('\u{cdc}', '\u{cde}', 86),
+    ('\u{ce0}', '\u{ce3}', 86),
+    ('\u{ce6}', '\u{cef}', 101),
+    ('\u{cf1}', '\u{cf3}', 86),
+    ('\u{d00}', '\u{d0c}', 92),
+    ('\u{d0e}', '\u{d10}', 92),
+    ('\u{d12}', '\u{d44}', 92),
+    ('\u{d46}', '\u{d48}', 92),
+    ('\u{d4a}', '\u{d4f}', 92),
+    ('\u{d54}', '\u{d63}', 92),
+    ('\u{d66}', '\u{d7f}', 92),
+    ('\u{d81}', '\u{d83}', 9),
+    ('\u{d85}', '\u{d96}', 9),
+    ('\u{d9a}', '\u{db1}', 9),
+    ('\u{db3}', '\u{dbb}', 9),
+    ('\u{dbd}', '\u{dbd}', 9),
+    ('\u{dc0}', '\u{dc6}', 9),
+    ('\u{dca}', '\u{dca}', 9),
+    ('\u{dcf}', '\u{dd4}', 9),
+    ('\u{dd6}', '\u{dd6}', 9),
+    ('\u{dd8}', '\u{ddf}', 9),
+    ('\u{de6}', '\u{def}', 9),
+    ('\u{df2}', '\u{df4}', 9),
+    ('\u{e01}', '\u{e3a}', 16),
+    ('\u{e40}', '\u{e5b}', 16),
+    ('\u{e81}', '\u{e82}', 103),
+    ('\u{e84}', '\u{e84}', 103),
+    ('\u{e86}', '\u{e8a}', 103),
+    ('\u{e8c}', '\u{ea3}', 103),
+    ('\u{ea5}', '\u{ea5}', 103),
+    ('\u{ea7}', '\u{ebd}', 103),
+    ('\u{ec0}', '\u{ec4}', 103),
+    ('\u{ec6}', '\u{ec6}', 103),
+    ('\u{ec8}', '\u{ece}', 103),
+    ('\u{ed0}', '\u{ed9}', 103),
+    ('\u{edc}', '\u{edf}', 103),
+    ('\u{f00}', '\u{f47}', 28),
+    ('\u{f49}', '\u{f6c}', 28),
+    ('\u{f71}', '\u{f97}', 28),
+    ('\u{f99}', '\u{fbc}', 28),
+    ('\u{fbe}', '\u{fcc}', 28),
+    ('\u{fce}', '\u{fd4}', 28),
+    ('\u{fd9}', '\u{fda}', 28),
+    ('\u{1000}', '\u{103f}', 60),
+    ('\u{1040}', '\u{1049}', 256),
+    ('\u{104a}', '\u{109f}', 60),
+    ('\u{10a0}', '\u{10c5}', 265),
+    ('\u{10c7}', '\u{10c7}', 265),
+    ('\u{10cd}', '\u{10cd}', 265),
+    ('\u{10d0}', '\u{10fa}', 265),
+    ('\u{10fb}', '\u{10fb}', 160),
+    ('\u{10fc}', '\u{10ff}', 265),
+    ('\u{1100}', '\u{11ff}', 221),
+    ('\u{1200}', '\u{1248}', 182),
+    ('\u{124a}', '\u{124d}', 182),
+    ('\u{1250}', '\u{1256}', 182),
+    ('\u{1258}', '\u{1258}', 182),
+    ('\u{125a}', '\u{125d}', 182),
+    ('\u{1260}', '\u{1288}', 182),
+    ('\u{128a}', '\u{128d}', 182),
+    ('\u{1290}', '\u{12b0}', 182),
+    ('\u{12b2}', '\u{12b5}', 182),
+    ('\u{12b8}', '\u{12be}', 182),
+    ('\u{12c0}', '\u{12c0}', 182),
+    ('\u{12c2}', '\u{12c5}', 182),
+    ('\u{12c8}', '\u{12d6}', 182),
+    ('\u{12d8}', '\u{1310}', 182),
+    ('\u{1312}', '\u{1315}', 182),
+    ('\u{1318}', '\u{135a}', 182),
+    ('\u{135d}', '\u{137c}', 182),
+    ('\u{1380}', '\u{1399}', 182),
+    ('\u{13a0}', '\u{13f5}', 257),
+    ('\u{13f8}', '\u{13fd}', 257),
+    ('\u{1400}', '\u{167f}', 125),
+    ('\u{1680}', '\u{169c}', 62),
+    ('\u{16a0}', '\u{16f8}', 100),
+    ('\u{1700}', '\u{1715}', 40),
+    ('\u{171f}', '\u{171f}', 40),
+    ('\u{1720}', '\u{1734}', 140),
+    ('\u{1735}', '\u{1736}', 143),
+    ('\u{1740}', '\u{1753}', 212),
+    ('\u{1760}', '\u{176c}', 19),
+    ('\u{176e}', '\u{1770}', 19),
+    ('\u{1772}', '\u{1773}', 19),
+    ('\u{1780}', '\u{17dd}', 102),
+    ('\u{17e0}', '\u{17e9}', 102),
+    ('\u{17f0}', '\u{17f9}', 102),
+    ('\u{1800}', '\u{1801}', 59),
+    ('\u{1802}', '\u{1803}', 118),
+    ('\u{1804}', '\u{1804}', 59),
+    ('\u{1805}', '\u{1805}', 118),
+    ('\u{1806}', '\u{1819}', 59),
+    ('\u{1820}', '\u{1878}', 59),
+    ('\u{1880}', '\u{18aa}', 59),
+    ('\u{18b0}', '\u{18f5}', 125),
+    ('\u{1900}', '\u{191e}', 88),
+    ('\u{1920}', '\u{192b}', 88),
+    ('\u{1930}', '\u{193b}', 88),
+    ('\u{1940}', '\u{1940}', 88),
+    ('\u{1944}', '\u{194f}', 88),
+    ('\u{1950}', '\u{196d}', 42),
+    ('\u{1970}', '\u{1974}', 42),
+    ('\u{1980}', '\u{19ab}', 6),
+    ('\u{19b0}', '\u{19c9}', 6),
+    ('\u{19d0}', '\u{19da}', 6),
+    ('\u{19de}', '\u{19df}', 6),
+    ('\u{19e0}', '\u{19ff}', 102),
+    ('\u{1a00}', '\u{1a1b}', 168),
+    ('\u{1a1e}', '\u{1a1f}', 168),
+    ('\u{1a20}', '\u{1a5e}', 87),
+    ('\u{1a60}', '\u{1a7c}', 87),
+    ('\u{1a7f}', '\u{1a89}', 87),
+    ('\u{1a90}', '\u{1a99}', 87),
+    ('\u{1aa0}', '\u{1aad}', 87),
+    ('\u{1b00}', '\u{1b4c}', 252),
+    ('\u{1b4e}', '\u{1b7f}', 252),
+    ('\u{1b80}', '\u{1bbf}', 20),
+    ('\u{1bc0}', '\u{1bf3}', 211),
+    ('\u{1bfc}', '\u{1bff}', 211),
+    ('\u{1c00}', '\u{1c37}', 70),
+    ('\u{1c3b}', '\u{1c49}', 70),
+    ('\u{1c4d}', '\u{1c4f}', 70),
+    ('\u{1c50}', '\u{1c7f}', 78),
+    ('\u{1c80}', '\u{1c8a}', 127),
+    ('\u{1c90}', '\u{1cba}', 265),
+    ('\u{1cbd}', '\u{1cbf}', 265),
+    ('\u{1cc0}', '\u{1cc7}', 20),
+    ('\u{1cd0}', '\u{1cd0}', 149),
+    ('\u{1cd1}', '\u{1cd1}', 172),
+    ('\u{1cd2}', '\u{1cd2}', 149),
+    ('\u{1cd3}', '\u{1cd3}', 146),
+    ('\u{1cd4}', '\u{1cd4}', 172),
+    ('\u{1cd5}', '\u{1cd5}', 201),
+    ('\u{1cd6}', '\u{1cd6}', 198),
+    ('\u{1cd7}', '\u{1cd7}', 177),
+    ('\u{1cd8}', '\u{1cd8}', 200),
+    ('\u{1cd9}', '\u{1cd9}', 173),
+    ('\u{1cda}', '\u{1cda}', 175),
+    ('\u{1cdb}', '\u{1cdb}', 172),
+    ('\u{1cdc}', '\u{1cdd}', 173),
+    ('\u{1cde}', '\u{1cdf}', 172),
+    ('\u{1ce0}', '\u{1ce0}', 173),
+    ('\u{1ce1}', '\u{1ce1}', 195),
+    ('\u{1ce2}', '\u{1ce2}', 178),
+    ('\u{1ce3}', '\u{1ce8}', 172),
+    ('\u{1ce9}', '\u{1ce9}', 179),
+    ('\u{1cea}', '\u{1cea}', 197),
+    ('\u{1ceb}', '\u{1ceb}', 176),
+    ('\u{1cec}', '\u{1cec}', 172),
+    ('\u{1ced}', '\u{1ced}', 199),
+    ('\u{1cee}', '\u{1cf1}', 172),
+    ('\u{1cf2}', '\u{1cf2}', 148),
+    ('\u{1cf3}', '\u{1cf3}', 144),
+    ('\u{1cf4}', '\u{1cf4}', 147),
+    ('\u{1cf5}', '\u{1cf6}', 195),
+    ('\u{1cf7}', '\u{1cf7}', 253),
+    ('\u{1cf8}', '\u{1cf9}', 144),
+    ('\u{1cfa}', '\u{1cfa}', 94),
+    ('\u{1d00}', '\u{1d25}', 49),
+    ('\u{1d26}', '\u{1d2a}', 185),
+    ('\u{1d2b}', '\u{1d2b}', 127),
+    ('\u{1d2c}', '\u{1d5c}', 49),
+    ('\u{1d5d}', '\u{1d61}', 185),
+    ('\u{1d62}', '\u{1d65}', 49),
+    ('\u{1d66}', '\u{1d6a}', 185),
+    ('\u{1d6b}', '\u{1d77}', 49),
+    ('\u{1d78}', '\u{1d78}', 127),
+    ('\u{1d79}', '\u{1dbe}', 49),
+    ('\u{1dbf}', '\u{1dc1}', 185),
+    ('\u{1df8}', '\u{1df8}', 129),
+    ('\u{1dfa}', '\u{1dfa}', 7),
+    ('\u{1e00}', '\u{1eff}', 49),
+    ('\u{1f00}', '\u{1f15}', 185),
+    ('\u{1f18}', '\u{1f1d}', 185),
+    ('\u{1f20}', '\u{1f45}', 185),
+    ('\u{1f48}', '\u{1f4d}', 185),
+    ('\u{1f50}', '\u{1f57}', 185),
+    ('\u{1f59}', '\u{1f59}', 185),
+    ('\u{1f5b}', '\u{1f5b}', 185),
+    ('\u{1f5d}', '\u{1f5d}', 185),
+    ('\u{1f5f}', '\u{1f7d}', 185),
+    ('\u{1f80}', '\u{1fb4}', 185),
+    ('\u{1fb6}', '\u{1fc4}', 185),
+    ('\u{1fc6}', '\u{1fd3}', 185),
+    ('\u{1fd6}', '\u{1fdb}', 185),
+    ('\u{1fdd}', '\u{1fef}', 185),
+    ('\u{1ff2}', '\u{1ff4}', 185),
+    ('\u{1ff6}', '\u{1ffe}', 185),
+    ('\u{202f}', '\u{202f}', 119),
+    ('\u{204f}', '\u{204f}', 277),
+    ('\u{205a}', '\u{205a}', 274),
+    ('\u{205d}', '\u{205d}', 275),
+    ('\u{2071}', '\u{2071}', 49),
+    ('\u{207f}', '\u{207f}', 49),
+    ('\u{2090}', '\u{209c}', 49),
+    ('\u{20f0}', '\u{20f0}', 145),
+    ('\u{2126}', '\u{2126}', 185),
+    ('\u{212a}', '\u{212b}', 49),
+    ('\u{2132}', '\u{2132}', 49),
+    ('\u{214e}', '\u{214e}', 49),
+    ('\u{2160}', '\u{2188}', 49),
+    ('\u{2800}', '\u{28ff}', 124),
+    ('\u{2c00}', '\u{2c5f}', 134),
+    ('\u{2c60}', '\u{2c7f}', 49),
+    ('\u{2c80}', '\u{2cf3}', 170),
+    ('\u{2cf9}', '\u{2cff}', 170),
+    ('\u{2d00}', '\u{2d25}', 265),
+    ('\u{2d27}', '\u{2d27}', 265),
+    ('\u{2d2d}', '\u{2d2d}', 265),
+    ('\u{2d30}', '\u{2d67}', 29),
+    ('\u{2d6f}', '\u{2d70}', 29),
+    ('\u{2d7f}', '\u{2d7f}', 29),
+    ('\u{2d80}', '\u{2d96}', 182),
+    ('\u{2da0}', '\u{2da6}', 182),
+    ('\u{2da8}', '\u{2dae}', 182),
+    ('\u{2db0}', '\u{2db6}', 182),
+    ('\u{2db8}', '\u{2dbe}', 182),
+    ('\u{2dc0}', '\u{2dc6}', 182),
+    ('\u{2dc8}', '\u{2dce}', 182),
+    ('\u{2dd0}', '\u{2dd6}', 182),
+    ('\u{2dd8}', '\u{2dde}', 182),
+    ('\u{2de0}', '\u{2dff}', 127),
+    ('\u{2e17}', '\u{2e17}', 171),
+    ('\u{2e30}', '\u{2e30}', 210),
+    ('\u{2e31}', '\u{2e31}', 273),
+    ('\u{2e3c}', '\u{2e3c}', 180),
+    ('\u{2e41}', '\u{2e41}', 271),
+    ('\u{2e43}', '\u{2e43}', 150),
+    ('\u{2e80}', '\u{2e99}', 231),
+    ('\u{2e9b}', '\u{2ef3}', 231),
+    ('\u{2f00}', '\u{2fd5}', 231),
+    ('\u{2ff0}', '\u{2fff}', 232),
+    ('\u{3001}', '\u{3001}', 239),
+    ('\u{3002}', '\u{3002}', 242),
+    ('\u{3003}', '\u{3003}', 243),
+    ('\u{3005}', '\u{3007}', 231),
+    ('\u{3008}', '\u{3009}', 240),
+    ('\u{300a}', '\u{300b}', 241),
+    ('\u{300c}', '\u{3011}', 244),
+    ('\u{3013}', '\u{3013}', 243),
+    ('\u{3014}', '\u{301b}', 244),
+    ('\u{301c}', '\u{301f}', 243),
+    ('\u{3021}', '\u{3029}', 231),
+    ('\u{302a}', '\u{302d}', 233),
+    ('\u{302e}', '\u{302f}', 221),
+    ('\u{3030}', '\u{3030}', 243),
+    ('\u{3031}', '\u{3035}', 237),
+    ('\u{3037}', '\u{3037}', 243),
+    ('\u{3038}', '\u{303b}', 231),
+    ('\u{303c}', '\u{303d}', 238),
+    ('\u{303e}', '\u{303f}', 231),
+    ('\u{3041}', '\u{3096}', 236),
+    ('\u{3099}', '\u{309c}', 237),
+    ('\u{309d}', '\u{309f}', 236),
+    ('\u{30a0}', '\u{30a0}', 237),
+    ('\u{30a1}', '\u{30fa}', 224),
+    ('\u{30fb}', '\u{30fb}', 244),
+    ('\u{30fc}', '\u{30fc}', 237),
+    ('\u{30fd}', '\u{30ff}', 224),
+    ('\u{3105}', '\u{312f}', 191),
+    ('\u{3131}', '\u{318e}', 221),
+    ('\u{3190}', '\u{319f}', 231),
+    ('\u{31a0}', '\u{31bf}', 191),
+    ('\u{31c0}', '\u{31e5}', 231),
+    ('\u{31ef}', '\u{31ef}', 232),
+    ('\u{31f0}', '\u{31ff}', 224),
+    ('\u{3200}', '\u{321e}', 221),
+    ('\u{3220}', '\u{3247}', 231),
+    ('\u{3260}', '\u{327e}', 221),
+    ('\u{3280}', '\u{32b0}', 231),
+    ('\u{32c0}', '\u{32cb}', 231),
+    ('\u{32d0}', '\u{32fe}', 224),
+    ('\u{32ff}', '\u{32ff}', 231),
+    ('\u{3300}', '\u{3357}', 224),
+    ('\u{3358}', '\u{3370}', 231),
+    ('\u{337b}', '\u{337f}', 231),
+    ('\u{33e0}', '\u{33fe}', 231),
+    ('\u{3400}', '\u{4dbf}', 231),
+    ('\u{4e00}', '\u{9fff}', 231),
+    ('\u{a000}', '\u{a48c}', 12),
+    ('\u{a490}', '\u{a4c6}', 12),
+    ('\u{a4d0}', '\u{a4ff}', 71),
+    ('\u{a500}', '\u{a62b}', 26),
+    ('\u{a640}', '\u{a66e}', 127),
+    ('\u{a66f}', '\u{a66f}', 150),
+    ('\u{a670}', '\u{a69f}', 127),
+    ('\u{a6a0}', '\u{a6f7}', 122),
+    ('\u{a700}', '\u{a707}', 230),
+    ('\u{a722}', '\u{a787}', 49),
+    ('\u{a78b}', '\u{a7dc}', 49),
+    ('\u{a7f1}', '\u{a7ff}', 49),
+    ('\u{a800}', '\u{a82c}', 43),
+    ('\u{a830}', '\u{a832}', 154),
+    ('\u{a833}', '\u{a835}', 153),
+    ('\u{a836}', '\u{a837}', 155),
+    ('\u{a838}', '\u{a838}', 156),
+    ('\u{a839}', '\u{a839}', 155),
+    ('\u{a840}', '\u{a877}', 114),
+    ('\u{a880}', '\u{a8c5}', 23),
+    ('\u{a8ce}', '\u{a8d9}', 23),
+    ('\u{a8e0}', '\u{a8f0}', 172),
+    ('\u{a8f1}', '\u{a8f1}', 196),
+    ('\u{a8f2}', '\u{a8f2}', 172),
+    ('\u{a8f3}', '\u{a8f3}', 174),
+    ('\u{a8f4}', '\u{a8ff}', 172),
+    ('\u{a900}', '\u{a92d}', 269),
+    ('\u{a92e}', '\u{a92e}', 270),
+    ('\u{a92f}', '\u{a92f}', 269),
+    ('\u{a930}', '\u{a953}', 66),
+    ('\u{a95f}', '\u{a95f}', 66),
+    ('\u{a960}', '\u{a97c}', 221),
+    ('\u{a980}', '\u{a9cd}', 189),
+    ('\u{a9cf}', '\u{a9cf}', 190),
+    ('\u{a9d0}', '\u{a9d9}', 189),
+    ('\u{a9de}', '\u{a9df}', 189),
+    ('\u{a9e0}', '\u{a9fe}', 60),
+    ('\u{aa00}', '\u{aa36}', 213),
+    ('\u{aa40}', '\u{aa4d}', 213),
+    ('\u{aa50}', '\u{aa59}', 213),
+    ('\u{aa5c}', '\u{aa5f}', 213),
+    ('\u{aa60}', '\u{aa7f}', 60),
+    ('\u{aa80}', '\u{aac2}', 41),
+    ('\u{aadb}', '\u{aadf}', 41),
+    ('\u{aae0}', '\u{aaf6}', 93),
+    ('\u{ab01}', '\u{ab06}', 182),
+    ('\u{ab09}', '\u{ab0e}', 182),
+    ('\u{ab11}', '\u{ab16}', 182),
+    ('\u{ab20}', '\u{ab26}', 182),
+    ('\u{ab28}', '\u{ab2e}', 182),
+    ('\u{ab30}', '\u{ab5a}', 49),
+    ('\u{ab5c}', '\u{ab64}', 49),
+    ('\u{ab65}', '\u{ab65}', 185),
+    ('\u{ab66}', '\u{ab69}', 49),
+    ('\u{ab70}', '\u{abbf}', 257),
+    ('\u{abc0}', '\u{abed}', 93),
+    ('\u{abf0}', '\u{abf9}', 93),
+    ('\u{ac00}', '\u{d7a3}', 221),
+    ('\u{d7b0}', '\u{d7c6}', 221),
+    ('\u{d7cb}', '\u{d7fb}', 221),
+    ('\u{f900}', '\u{fa6d}', 231),
+    ('\u{fa70}', '\u{fad9}', 231),
+    ('\u{fb00}', '\u{fb06}', 49),
+    ('\u{fb13}', '\u{fb17}', 165),
+    ('\u{fb1d}', '\u{fb36}', 222),
+    ('\u{fb38}', '\u{fb3c}', 222),
+    ('\u{fb3e}', '\u{fb3e}', 222),
+    ('\u{fb40}', '\u{fb41}', 222),
+    ('\u{fb43}', '\u{fb44}', 222),
+    ('\u{fb46}', '\u{fb4f}', 222),
+    ('\u{fb50}', '\u{fd3d}', 245),
+    ('\u{fd3e}', '\u{fd3f}', 251),
+    ('\u{fd40}', '\u{fdcf}', 245),
+    ('\u{fdf0}', '\u{fdf1}', 245),
+    ('\u{fdf2}', '\u{fdf2}', 246),
+    ('\u{fdf3}', '\u{fdfc}', 245),
+    ('\u{fdfd}', '\u{fdfd}', 246),
+    ('\u{fdfe}', '\u{fdff}', 245),
+    ('\u{fe2e}', '\u{fe2f}', 127),
+    ('\u{fe45}', '\u{fe46}', 243),
+    ('\u{fe70}', '\u{fe74}', 245),
+    ('\u{fe76}', '\u{fefc}', 245),
+    ('\u{ff21}', '\u{ff3a}', 49),
+    ('\u{ff41}', '\u{ff5a}', 49),
+    ('\u{ff61}', '\u{ff65}', 244),
+    ('\u{ff66}', '\u{ff6f}', 224),
+    ('\u{ff70}', '\u{ff70}', 237),
+    ('\u{ff71}', '\u{ff9d}', 224),
+    ('\u{ff9e}', '\u{ff9f}', 237),
+    ('\u{ffa0}', '\u{ffbe}', 221),
+    ('\u{ffc2}', '\u{ffc7}', 221),
+    ('\u{ffca}', '\u{ffcf}', 221),
+    ('\u{ffd2}', '\u{ffd7}', 221),
+    ('\u{ffda}', '\u{ffdc}', 221),
+    ('\u{10000}', '\u{1000b}', 55),
+    ('\u{1000d}', '\u{10026}', 55),
+    ('\u{10028}', '\u{1003a}', 55),
+    ('\u{1003c}', '\u{1003d}', 55),
+    ('\u{1003f}', '\u{1004d}', 55),
+    ('\u{10050}', '\u{1005d}', 55),
+    ('\u{10080}', '\u{100fa}', 55),
+    ('\u{10100}', '\u{10101}', 280),
+    ('\u{10102}', '\u{10102}', 261),
+    ('\u{10107}', '\u{10133}', 262),
+    ('\u{10137}', '\u{1013f}', 261),
+    ('\u{10140}', '\u{1018e}', 185),
+    ('\u{101a0}', '\u{101a0}', 185),
+    ('\u{10280}', '\u{1029c}', 89),
+    ('\u{102a0}', '\u{102d0}', 169),
+    ('\u{102e0}', '\u{102fb}', 193),
+    ('\u{10300}', '\u{10323}', 142),
+    ('\u{1032d}', '\u{1032f}', 142),
+    ('\u{10330}', '\u{1034a}', 266),
+    ('\u{10350}', '\u{1037a}', 98),
+    ('\u{10380}', '\u{1039d}', 14),
+    ('\u{1039f}', '\u{1039f}', 14),
+    ('\u{103a0}', '\u{103c3}', 25),
+    ('\u{103c8}', '\u{103d5}', 25),
+    ('\u{10400}', '\u{1044f}', 132),
+    ('\u{10450}', '\u{1047f}', 46),
+    ('\u{10480}', '\u{1049d}', 97),
+    ('\u{104a0}', '\u{104a9}', 97),
+    ('\u{104b0}', '\u{104d3}', 79),
+    ('\u{104d8}', '\u{104fb}', 79),
+    ('\u{10500}', '\u{10527}', 264),
+    ('\u{10530}', '\u{10563}', 163),
+    ('\u{1056f}', '\u{1056f}', 163),
+    ('\u{10570}', '\u{1057a}', 37),
+    ('\u{1057c}', '\u{1058a}', 37),
+    ('\u{1058c}', '\u{10592}', 37),
+    ('\u{10594}', '\u{10595}', 37),
+    ('\u{10597}', '\u{105a1}', 37),
+    ('\u{105a3}', '\u{105b1}', 37),
+    ('\u{105b3}', '\u{105b9}', 37),
+    ('\u{105bb}', '\u{105bc}', 37),
+    ('\u{105c0}', '\u{105f3}', 15),
+    ('\u{10600}', '\u{10736}', 104),
+    ('\u{10740}', '\u{10755}', 104),
+    ('\u{10760}', '\u{10767}', 104),
+    ('\u{10780}', '\u{10785}', 49),
+    ('\u{10787}', '\u{107b0}', 49),
+    ('\u{107b2}', '\u{107ba}', 49),
+    ('\u{10800}', '\u{10805}', 260),
+    ('\u{10808}', '\u{10808}', 260),
+    ('\u{1080a}', '\u{10835}', 260),
+    ('\u{10837}', '\u{10838}', 260),
+    ('\u{1083c}', '\u{1083c}', 260),
+    ('\u{1083f}', '\u{1083f}', 260),
+    ('\u{10840}', '\u{10855}', 121),
+    ('\u{10857}', '\u{1085f}', 121),
+    ('\u{10860}', '\u{1087f}', 64),
+    ('\u{10880}', '\u{1089e}', 61),
+    ('\u{108a7}', '\u{108af}', 61),
+    ('\u{108e0}', '\u{108f2}', 187),
+    ('\u{108f4}', '\u{108f5}', 187),
+    ('\u{108fb}', '\u{108ff}', 187),
+    ('\u{10900}', '\u{1091b}', 99),
+    ('\u{1091f}', '\u{1091f}', 99),
+    ('\u{10920}', '\u{10939}', 105),
+    ('\u{1093f}', '\u{1093f}', 105),
+    ('\u{10940}', '\u{10959}', 34),
+    ('\u{10980}', '\u{1099f}', 58),
+    ('\u{109a0}', '\u{109b7}', 107),
+    ('\u{109bc}', '\u{109cf}', 107),
+    ('\u{109d2}', '\u{109ff}', 107),
+    ('\u{10a00}', '\u{10a03}', 85),
+    ('\u{10a05}', '\u{10a06}', 85),
+    ('\u{10a0c}', '\u{10a13}', 85),
+    ('\u{10a15}', '\u{10a17}', 85),
+    ('\u{10a19}', '\u{10a35}', 85),
+    ('\u{10a38}', '\u{10a3a}', 85),
+    ('\u{10a3f}', '\u{10a48}', 85),
+    ('\u{10a50}', '\u{10a58}', 85),
+    ('\u{10a60}', '\u{10a7f}', 11),
+    ('\u{10a80}', '\u{10a9f}', 110),
+    ('\u{10ac0}', '\u{10ae6}', 106),
+    ('\u{10aeb}', '\u{10af1}', 106),
+    ('\u{10af2}', '\u{10af2}', 117),
+    ('\u{10af3}', '\u{10af6}', 106),
+    ('\u{10b00}', '\u{10b35}', 209),
+    ('\u{10b39}', '\u{10b3f}', 209),
+    ('\u{10b40}', '\u{10b55}', 115),
+    ('\u{10b58}', '\u{10b5f}', 115),
+    ('\u{10b60}', '\u{10b72}', 65),
+    ('\u{10b78}', '\u{10b7f}', 65),
+    ('\u{10b80}', '\u{10b91}', 81),
+    ('\u{10b99}', '\u{10b9c}', 81),
+    ('\u{10ba9}', '\u{10baf}', 81),
+    ('\u{10c00}', '\u{10c48}', 112),
+    ('\u{10c80}', '\u{10cb2}', 268),
+    ('\u{10cc0}', '\u{10cf2}', 268),
+    ('\u{10cfa}', '\u{10cff}', 268),
+    ('\u{10d00}', '\u{10d27}', 82),
+    ('\u{10d30}', '\u{10d39}', 82),
+    ('\u{10d40}', '\u{10d65}', 217),
+    ('\u{10d69}', '\u{10d85}', 217),
+    ('\u{10d8e}', '\u{10d8f}', 217),
+    ('\u{10e60}', '\u{10e7e}', 245),
+    ('\u{10e80}', '\u{10ea9}', 0),
+    ('\u{10eab}', '\u{10ead}', 0),
+    ('\u{10eb0}', '\u{10eb1}', 0),
+    ('\u{10ec2}', '\u{10ec7}', 245),
+    ('\u{10ed0}', '\u{10ed8}', 245),
+    ('\u{10efa}', '\u{10eff}', 245),
+    ('\u{10f00}', '\u{10f27}', 33),
+    ('\u{10f30}', '\u{10f59}', 21),
+    ('\u{10f70}', '\u{10f89}', 113),
+    ('\u{10fb0}', '\u{10fcb}', 126),
+    ('\u{10fe0}', '\u{10ff6}', 133),
+    ('\u{11000}', '\u{1104d}', 254),
+    ('\u{11052}', '\u{11075}', 254),
+    ('\u{1107f}', '\u{1107f}', 254),
+    ('\u{11080}', '\u{110c2}', 69),
+    ('\u{110cd}', '\u{110cd}', 69),
+    ('\u{110d0}', '\u{110e8}', 44),
+    ('\u{110f0}', '\u{110f9}', 44),
+    ('\u{11100}', '\u{11134}', 255),
+    ('\u{11136}', '\u{11147}', 255),
+    ('\u{11150}', '\u{11176}', 56),
+    ('\u{11180}', '\u{111df}', 10),
+    ('\u{111e1}', '\u{111f4}', 9),
+    ('\u{11200}', '\u{11211}', 47),
+    ('\u{11213}', '\u{11241}', 47),
+    ('\u{11280}', '\u{11286}', 109),
+    ('\u{11288}', '\u{11288}', 109),
+    ('\u{1128a}', '\u{1128d}', 109),
+    ('\u{1128f}', '\u{1129d}', 109),
+    ('\u{1129f}', '\u{112a9}', 109),
+    ('\u{112b0}', '\u{112ea}', 45),
+    ('\u{112f0}', '\u{112f9}', 45),
+    ('\u{11300}', '\u{11300}', 136),
+    ('\u{11301}', '\u{11301}', 137),
+    ('\u{11302}', '\u{11302}', 136),
+    ('\u{11303}', '\u{11303}', 137),
+    ('\u{11305}', '\u{1130c}', 136),
+    ('\u{1130f}', '\u{11310}', 136),
+    ('\u{11313}', '\u{11328}', 136),
+    ('\u{1132a}', '\u{11330}', 136),
+    ('\u{11332}', '\u{11333}', 136),
+    ('\u{11335}', '\u{11339}', 136),
+    ('\u{1133b}', '\u{1133c}', 137),
+    ('\u{1133d}', '\u{11344}', 136),
+    ('\u{11347}', '\u{11348}', 136),
+    ('\u{1134b}', '\u{1134d}', 136),
+    ('\u{11350}', '\u{11350}', 136),
+    ('\u{11357}', '\u{11357}', 136),
+    ('\u{1135d}', '\u{11363}', 136),
+    ('\u{11366}', '\u{1136c}', 136),
+    ('\u{11370}', '\u{11374}', 136),
+    ('\u{11380}', '\u{11389}', 2),
+    ('\u{1138b}', '\u{1138b}', 2),
+    ('\u{1138e}', '\u{1138e}', 2),
+    ('\u{11390}', '\u{113b5}', 2),
+    ('\u{113b7}', '\u{113c0}', 2),
+    ('\u{113c2}', '\u{113c2}', 2),
+    ('\u{113c5}', '\u{113c5}', 2),
+    ('\u{113c7}', '\u{113ca}', 2),
+    ('\u{113cc}', '\u{113d5}', 2),
+    ('\u{113d7}', '\u{113d8}', 2),
+    ('\u{113e1}', '\u{113e2}', 2),
+    ('\u{11400}', '\u{1145b}', 77),
+    ('\u{1145d}', '\u{11461}', 77),
+    ('\u{11480}', '\u{114c7}', 39),
+    ('\u{114d0}', '\u{114d9}', 39),
+    ('\u{11580}', '\u{115b5}', 22),
+    ('\u{115b8}', '\u{115dd}', 22),
+    ('\u{11600}', '\u{11644}', 108),
+    ('\u{11650}', '\u{11659}', 108),
+    ('\u{11660}', '\u{1166c}', 59),
+    ('\u{11680}', '\u{116b9}', 31),
+    ('\u{116c0}', '\u{116c9}', 31),
+    ('\u{116d0}', '\u{116e3}', 60),
+    ('\u{11700}', '\u{1171a}', 208),
+    ('\u{1171d}', '\u{1172b}', 208),
+    ('\u{11730}', '\u{11746}', 208),
+    ('\u{11800}', '\u{1183b}', 263),
+    ('\u{118a0}', '\u{118f2}', 1),
+    ('\u{118ff}', '\u{118ff}', 1),
+    ('\u{11900}', '\u{11906}', 215),
+    ('\u{11909}', '\u{11909}', 215),
+    ('\u{1190c}', '\u{11913}', 215),
+    ('\u{11915}', '\u{11916}', 215),
+    ('\u{11918}', '\u{11935}', 215),
+    ('\u{11937}', '\u{11938}', 215),
+    ('\u{1193b}', '\u{11946}', 215),
+    ('\u{11950}', '\u{11959}', 215),
+    ('\u{119a0}', '\u{119a7}', 94),
+    ('\u{119aa}', '\u{119d7}', 94),
+    ('\u{119da}', '\u{119e4}', 94),
+    ('\u{11a00}', '\u{11a47}', 24),
+    ('\u{11a50}', '\u{11aa2}', 8),
+    ('\u{11ab0}', '\u{11abf}', 125),
+    ('\u{11ac0}', '\u{11af8}', 80),
+    ('\u{11b00}', '\u{11b09}', 172),
+    ('\u{11b60}', '\u{11b67}', 10),
+    ('\u{11bc0}', '\u{11be1}', 32),
+    ('\u{11bf0}', '\u{11bf9}', 32),
+    ('\u{11c00}', '\u{11c08}', 167),
+    ('\u{11c0a}', '\u{11c36}', 167),
+    ('\u{11c38}', '\u{11c45}', 167),
+    ('\u{11c50}', '\u{11c6c}', 167),
+    ('\u{11c70}', '\u{11c8f}', 57),
+    ('\u{11c92}', '\u{11ca7}', 57),
+    ('\u{11ca9}', '\u{11cb6}', 57),
+    ('\u{11d00}', '\u{11d06}', 218),
+    ('\u{11d08}', '\u{11d09}', 218),
+    ('\u{11d0b}', '\u{11d36}', 218),
+    ('\u{11d3a}', '\u{11d3a}', 218),
+    ('\u{11d3c}', '\u{11d3d}', 218),
+    ('\u{11d3f}', '\u{11d47}', 218),
+    ('\u{11d50}', '\u{11d59}', 218),
+    ('\u{11d60}', '\u{11d65}', 184),
+    ('\u{11d67}', '\u{11d68}', 184),
+    ('\u{11d6a}', '\u{11d8e}', 184),
+    ('\u{11d90}', '\u{11d91}', 184),
+    ('\u{11d93}', '\u{11d98}', 184),
+    ('\u{11da0}', '\u{11da9}', 184),
+    ('\u{11db0}', '\u{11ddb}', 27),
+    ('\u{11de0}', '\u{11de9}', 27),
+    ('\u{11ee0}', '\u{11ef8}', 72),
+    ('\u{11f00}', '\u{11f10}', 67),
+    ('\u{11f12}', '\u{11f3a}', 67),
+    ('\u{11f3e}', '\u{11f5a}', 67),
+    ('\u{11fb0}', '\u{11fb0}', 71),
+    ('\u{11fc0}', '\u{11fcf}', 18),
+    ('\u{11fd0}', '\u{11fd1}', 137),
+    ('\u{11fd2}', '\u{11fd2}', 18),
+    ('\u{11fd3}', '\u{11fd3}', 137),
+    ('\u{11fd4}', '\u{11ff1}', 18),
+    ('\u{11fff}', '\u{11fff}', 18),
+    ('\u{12000}', '\u{12399}', 36),
+    ('\u{12400}', '\u{1246e}', 36),
+    ('\u{12470}', '\u{12474}', 36),
+    ('\u{12480}', '\u{12543}', 36),
+    ('\u{12f90}', '\u{12ff2}', 214),
+    ('\u{13000}', '\u{13455}', 216),
+    ('\u{13460}', '\u{143fa}', 216),
+    ('\u{14400}', '\u{14646}', 141),
+    ('\u{16100}', '\u{16139}', 267),
+    ('\u{16800}', '\u{16a38}', 122),
+    ('\u{16a40}', '\u{16a5e}', 75),
+    ('\u{16a60}', '\u{16a69}', 75),
+    ('\u{16a6e}', '\u{16a6f}', 75),
+    ('\u{16a70}', '\u{16abe}', 3),
+    ('\u{16ac0}', '\u{16ac9}', 3),
+    ('\u{16ad0}', '\u{16aed}', 166),
+    ('\u{16af0}', '\u{16af5}', 166),
+    ('\u{16b00}', '\u{16b45}', 188),
+    ('\u{16b50}', '\u{16b59}', 188),
+    ('\u{16b5b}', '\u{16b61}', 188),
+    ('\u{16b63}', '\u{16b77}', 188),
+    ('\u{16b7d}', '\u{16b8f}', 188),
+    ('\u{16d40}', '\u{16d79}', 48),
+    ('\u{16e40}', '\u{16e9a}', 73),
+    ('\u{16ea0}', '\u{16eb8}', 123),
+    ('\u{16ebb}', '\u{16ed3}', 123),
+    ('\u{16f00}', '\u{16f4a}', 74),
+    ('\u{16f4f}', '\u{16f87}', 74),
+    ('\u{16f8f}', '\u{16f9f}', 74),
+    ('\u{16fe0}', '\u{16fe0}', 30),
+    ('\u{16fe1}', '\u{16fe1}', 111),
+    ('\u{16fe2}', '\u{16fe3}', 231),
+    ('\u{16fe4}', '\u{16fe4}', 68),
+    ('\u{16ff0}', '\u{16ff6}', 231),
+    ('\u{17000}', '\u{18aff}', 30),
+    ('\u{18b00}', '\u{18cd5}', 68),
+    ('\u{18cff}', '\u{18cff}', 68),
+    ('\u{18d00}', '\u{18d1e}', 30),
+    ('\u{18d80}', '\u{18df2}', 30),
+    ('\u{1aff0}', '\u{1aff3}', 224),
+    ('\u{1aff5}', '\u{1affb}', 224),
+    ('\u{1affd}', '\u{1affe}', 224),
+    ('\u{1b000}', '\u{1b000}', 224),
+    ('\u{1b001}', '\u{1b11f}', 236),
+    ('\u{1b120}', '\u{1b122}', 224),
+    ('\u{1b132}', '\u{1b132}', 236),
+    ('\u{1b150}', '\u{1b152}', 236),
+    ('\u{1b155}', '\u{1b155}', 224),
+    ('\u{1b164}', '\u{1b167}', 224),
+    ('\u{1b170}', '\u{1b2fb}', 111),
+    ('\u{1bc00}', '\u{1bc6a}', 180),
+    ('\u{1bc70}', '\u{1bc7c}', 180),
+    ('\u{1bc80}', '\u{1bc88}', 180),
+    ('\u{1bc90}', '\u{1bc99}', 180),
+    ('\u{1bc9c}', '\u{1bca3}', 180),
+    ('\u{1d200}', '\u{1d245}', 185),
+    ('\u{1d360}', '\u{1d371}', 231),
+    ('\u{1d800}', '\u{1da8b}', 35),
+    ('\u{1da9b}', '\u{1da9f}', 35),
+    ('\u{1daa1}', '\u{1daaf}', 35),
+    ('\u{1df00}', '\u{1df1e}', 49),
+    ('\u{1df25}', '\u{1df2a}', 49),
+    ('\u{1e000}', '\u{1e006}', 134),
+    ('\u{1e008}', '\u{1e018}', 134),
+    ('\u{1e01b}', '\u{1e021}', 134),
+    ('\u{1e023}', '\u{1e024}', 134),
+    ('\u{1e026}', '\u{1e02a}', 134),
+    ('\u{1e030}', '\u{1e06d}', 127),
+    ('\u{1e08f}', '\u{1e08f}', 127),
+    ('\u{1e100}', '\u{1e12c}', 223),
+    ('\u{1e130}', '\u{1e13d}', 223),
+    ('\u{1e140}', '\u{1e149}', 223),
+    ('\u{1e14e}', '\u{1e14f}', 223),
+    ('\u{1e290}', '\u{1e2ae}', 38),
+    ('\u{1e2c0}', '\u{1e2f9}', 13),
+    ('\u{1e2ff}', '\u{1e2ff}', 13),
+    ('\u{1e4d0}', '\u{1e4f9}', 76),
+    ('\u{1e5d0}', '\u{1e5fa}', 96),
+    ('\u{1e5ff}', '\u{1e5ff}', 96),
+    ('\u{1e6c0}', '\u{1e6de}', 5),
+    ('\u{1e6e0}', '\u{1e6f5}', 5),
+    ('\u{1e6fe}', '\u{1e6ff}', 5),
+    ('\u{1e7e0}', '\u{1e7e6}', 182),
+    ('\u{1e7e8}', '\u{1e7eb}', 182),
+    ('\u{1e7ed}', '\u{1e7ee}', 182),
+    ('\u{1e7f0}', '\u{1e7fe}', 182),
+    ('\u{1e800}', '\u{1e8c4}', 91),
+    ('\u{1e8c7}', '\u{1e8d6}', 91),
+    ('\u{1e900}', '\u{1e94b}', 120),
+    ('\u{1e950}', '\u{1e959}', 120),
+    ('\u{1e95e}', '\u{1e95f}', 120),
+    ('\u{1ee00}', '\u{1ee03}', 245),
+    ('\u{1ee05}', '\u{1ee1f}', 245),
+    ('\u{1ee21}', '\u{1ee22}', 245),
+    ('\u{1ee24}', '\u{1ee24}', 245),
+    ('\u{1ee27}', '\u{1ee27}', 245),
+    ('\u{1ee29}', '\u{1ee32}', 245),
+    ('\u{1ee34}', '\u{1ee37}', 245),
+    ('\u{1ee39}', '\u{1ee39}', 245),
+    ('\u{1ee3b}', '\u{1ee3b}', 245),
+    ('\u{1ee42}', '\u{1ee42}', 245),
+    ('\u{1ee47}', '\u{1ee47}', 245),
+    ('\u{1ee49}', '\u{1ee49}', 245),
+    ('\u{1ee4b}', '\u{1ee4b}', 245),
+    ('\u{1ee4d}', '\u{1ee4f}', 245),
+    ('\u{1ee51}', '\u{1ee52}', 245),
+    ('\u{1ee54}', '\u{1ee54}', 245),
+    ('\u{1ee57}', '\u{1ee57}', 245),
+    ('\u{1ee59}', '\u{1ee59}', 245),
+    ('\u{1ee5b}', '\u{1ee5b}', 245),
+    ('\u{1ee5d}', '\u{1ee5d}', 245),
+    ('\u{1ee5f}', '\u{1ee5f}', 245),
+    ('\u{1ee61}', '\u{1ee62}', 245),
+    ('\u{1ee64}', '\u{1ee64}', 245),
+    ('\u{1ee67}', '\u{1ee6a}', 245),
+    ('\u{1ee6c}', '\u{1ee72}', 245),
+    ('\u{1ee74}', '\u{1ee77}', 245),
+    ('\u{1ee79}', '\u{1ee7c}', 245),
+    ('\u{1ee7e}', '\u{1ee7e}', 245),
+    ('\u{1ee80}', '\u{1ee89}', 245),
+    ('\u{1ee8b}', '\u{1ee9b}', 245),
+    ('\u{1eea1}', '\u{1eea3}', 245),
+    ('\u{1eea5}', '\u{1eea9}', 245),
+    ('\u{1eeab}', '\u{1eebb}', 245),
+    ('\u{1eef0}', '\u{1eef1}', 245),
+    ('\u{1f200}', '\u{1f200}', 236),
+    ('\u{1f250}', '\u{1f251}', 231),
+    ('\u{20000}', '\u{2a6df}', 231),
+    ('\u{2a700}', '\u{2b81d}', 231),
+    ('\u{2b820}', '\u{2cead}', 231),
+    ('\u{2ceb0}', '\u{2ebe0}', 231),
+    ('\u{2ebf0}', '\u{2ee5d}', 231),
+    ('\u{2f800}', '\u{2fa1d}', 231),
+    ('\u{30000}', '\u{3134a}', 231),
+    ('\u{31350}', '\u{33479}', 231),
+];
+
+static CONTEXT_O_SCRIPT_TABLE: &[(char, char, ContextOScript)] = &[
+    ('\u{370}', '\u{373}', ContextOScript::Greek),
+    ('\u{375}', '\u{377}', ContextOScript::Greek),
+    ('\u{37a}', '\u{37d}', ContextOScript::Greek),
+    ('\u{37f}', '\u{37f}', ContextOScript::Greek),
+    ('\u{384}', '\u{384}', ContextOScript::Greek),
+    ('\u{386}', '\u{386}', ContextOScript::Greek),
+    ('\u{388}', '\u{38a}', ContextOScript::Greek),
+    ('\u{38c}', '\u{38c}', ContextOScript::Greek),
+    ('\u{38e}', '\u{3a1}', ContextOScript::Greek),
+    ('\u{3a3}', '\u{3e1}', ContextOScript::Greek),
+    ('\u{3f0}', '\u{3ff}', ContextOScript::Greek),
+    ('\u{591}', '\u{5c7}', ContextOScript::Hebrew),
+    ('\u{5d0}', '\u{5ea}', ContextOScript::Hebrew),
+    ('\u{5ef}', '\u{5f4}', ContextOScript::Hebrew),
+    ('\u{1d26}', '\u{1d2a}', ContextOScript::Greek),
+    ('\u{1d5d}', '\u{1d61}', ContextOScript::Greek),
+    ('\u{1d66}', '\u{1d6a}', ContextOScript::Greek),
+    ('\u{1dbf}', '\u{1dbf}', ContextOScript::Greek),
+    ('\u{1f00}', '\u{1f15}', ContextOScript::Greek),
+    ('\u{1f18}', '\u{1f1d}', ContextOScript::Greek),
+    ('\u{1f20}', '\u{1f45}', ContextOScript::Greek),
+    ('\u{1f48}', '\u{1f4d}', ContextOScript::Greek),
+    ('\u{1f50}', '\u{1f57}', ContextOScript::Greek),
+    ('\u{1f59}', '\u{1f59}', ContextOScript::Greek),
+    ('\u{1f5b}', '\u{1f5b}', ContextOScript::Greek),
+    ('\u{1f5d}', '\u{1f5d}', ContextOScript::Greek),
+    ('\u{1f5f}', '\u{1f7d}', ContextOScript::Greek),
+    ('\u{1f80}', '\u{1fb4}', ContextOScript::Greek),
+    ('\u{1fb6}', '\u{1fc4}', ContextOScript::Greek),
+    ('\u{1fc6}', '\u{1fd3}', ContextOScript::Greek),
+    ('\u{1fd6}', '\u{1fdb}', ContextOScript::Greek),
+    ('\u{1fdd}', '\u{1fef}', ContextOScript::Greek),
+    ('\u{1ff2}', '\u{1ff4}', ContextOScript::Greek),
+    ('\u{1ff6}', '\u{1ffe}', ContextOScript::Greek),
+    ('\u{2126}', '\u{2126}', ContextOScript::Greek),
+    ('\u{2e80}', '\u{2e99}', ContextOScript::Han),
+    ('\u{2e9b}', '\u{2ef3}', ContextOScript::Han),
+    ('\u{2f00}', '\u{2fd5}', ContextOScript::Han),
+    ('\u{3005}', '\u{3005}', ContextOScript::Han),
+    ('\u{3007}', '\u{3007}', ContextOScript::Han),
+    ('\u{3021}', '\u{3029}', ContextOScript::Han),
+    ('\u{3038}', '\u{303b}', ContextOScript::Han),
+    ('\u{3041}', '\u{3096}', ContextOScript::Hiragana),
+    ('\u{309d}', '\u{309f}', ContextOScript::Hiragana),
+    ('\u{30a1}', '\u{30fa}', ContextOScript::Katakana),
+    ('\u{30fd}', '\u{30ff}', ContextOScript::Katakana),
+    ('\u{31f0}', '\u{31ff}', ContextOScript::Katakana),
+    ('\u{32d0}', '\u{32fe}', ContextOScript::Katakana),
+    ('\u{3300}', '\u{3357}', ContextOScript::Katakana),
+    ('\u{3400}', '\u{4dbf}', ContextOScript::Han),
+    ('\u{4e00}', '\u{9fff}', ContextOScript::Han),
+    ('\u{ab65}', '\u{ab65}', ContextOScript::Greek),
+    ('\u{f900}', '\u{fa6d}', ContextOScript::Han),
+    ('\u{fa70}', '\u{fad9}', ContextOScript::Han),
+    ('\u{fb1d}', '\u{fb36}', ContextOScript::Hebrew),
+    ('\u{fb38}', '\u{fb3c}', ContextOScript::Hebrew),
+    ('\u{fb3e}', '\u{fb3e}', ContextOScript::Hebrew),
+    ('\u{fb40}', '\u{fb41}', ContextOScript::Hebrew),
+    ('\u{fb43}', '\u{fb44}', ContextOScript::Hebrew),
+    ('\u{fb46}', '\u{fb4f}', ContextOScript::Hebrew),
+    ('\u{ff66}', '\u{ff6f}', ContextOScript::Katakana),
+    ('\u{ff71}', '\u{ff9d}', ContextOScript::Katakana),
+    ('\u{10140}', '\u{1018e}', ContextOScript::Greek),
+    ('\u{101a0}', '\u{101a0}', ContextOScript::Greek),
+    ('\u{16fe2}', '\u{16fe3}', ContextOScript::Han),
+    ('\u{16ff0}', '\u{16ff6}', ContextOScript::Han),
+    ('\u{1aff0}', '\u{1aff3}', ContextOScript::Katakana),
+    ('\u{1aff5}', '\u{1affb}', ContextOScript::Katakana),
+    ('\u{1affd}', '\u{1affe}', ContextOScript::Katakana),
+    ('\u{1b000}', '\u{1b000}', ContextOScript::Katakana),
+    ('\u{1b001}', '\u{1b11f}', ContextOScript::Hiragana),
+    ('\u{1b120}', '\u{1b122}', ContextOScript::Katakana),
+    ('\u{1b132}', '\u{1b132}', ContextOScript::Hiragana),
+    ('\u{1b150}', '\u{1b152}', ContextOScript::Hiragana),
+    ('\u{1b155}', '\u{1b155}', ContextOScript::Katakana),
+    ('\u{1b164}', '\u{1b167}', ContextOScript::Katakana),
+    ('\u{1d200}', '\u{1d245}', ContextOScript::Greek),
+    ('\u{1f200}', '\u{1f200}', ContextOScript::Hiragana),
+    ('\u{20000}', '\u{2a6df}', ContextOScript::Han),
+    ('\u{2a700}', '\u{2b81d}', ContextOScript::Han),
+    ('\u{2b820}', '\u{2cead}', ContextOScript::Han),
+    ('\u{2ceb0}', '\u{2ebe0}', ContextOScript::Han),
+    ('\u{2ebf0}', '\u{2ee5d}', ContextOScript::Han),
+    ('\u{2f800}', '\u{2fa1d}', ContextOScript::Han),
+    ('\u{30000}', '\u{3134a}', ContextOScript::Han),
+    ('\u{31350}', '\u{33479}', ContextOScript::Han),
+];
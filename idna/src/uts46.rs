@@ -11,12 +11,18 @@
 
 use self::Mapping::*;
 use crate::punycode;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::string::String;
+use std::vec::Vec;
 use std::{error::Error as StdError, fmt};
 use unicode_bidi::{bidi_class, BidiClass};
-use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::char::{canonical_combining_class, is_combining_mark};
 use unicode_normalization::{is_nfc, UnicodeNormalization};
 
 include!("uts46_mapping_table.rs");
+include!("joining_type_table.rs");
+include!("script_table.rs");
 
 const PUNYCODE_PREFIX: &str = "xn--";
 
@@ -69,17 +75,106 @@ fn find_char(codepoint: char) -> &'static Mapping {
     }
 }
 
+/// The disposition of a character in the UTS #46 mapping table, see `map_status`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(clippy::manual_non_exhaustive)] // #[non_exhaustive] is 1.40, MSRV is 1.36
+pub enum MappingStatus {
+    /// The character is valid and kept as is.
+    Valid,
+    /// The character is removed.
+    Ignored,
+    /// The character is replaced with the given string.
+    Mapped(&'static str),
+    /// The character is replaced with the given string in transitional processing,
+    /// and kept as is otherwise.
+    Deviation(&'static str),
+    /// The character is not allowed.
+    Disallowed,
+    /// The character is valid, but disallowed with `Config::use_std3_ascii_rules`.
+    DisallowedStd3Valid,
+    /// The character is replaced with the given string,
+    /// but disallowed with `Config::use_std3_ascii_rules`.
+    DisallowedStd3Mapped(&'static str),
+    /// The character is valid, but disallowed with `Config::use_idna_2008_rules`.
+    DisallowedIdna2008,
+    /// Unused variant enable non-exhaustive matching
+    #[doc(hidden)]
+    __FutureProof,
+}
+
+/// Map each character of `input` through the UTS #46 mapping table, lazily.
+///
+/// This is only the mapping step of processing: ignored characters are removed and
+/// mapped ones are replaced, following `config.transitional_processing` for deviations.
+/// The output is not normalized and no validity criteria are checked, so disallowed
+/// characters are yielded unchanged.
+///
+/// ```rust
+/// let mapped: String = idna::map_chars("Bücher\u{AD}.EXAMPLE", idna::Config::default()).collect();
+/// assert_eq!(mapped, "bücher.example");
+/// ```
+pub fn map_chars(input: &str, config: Config) -> impl Iterator<Item = char> + '_ {
+    Mapper::new(input.chars(), config)
+}
+
+/// Look up the disposition of a character in the UTS #46 mapping table.
+///
+/// This is the mapping step of processing, before any of the validity criteria are checked.
+pub fn map_status(c: char) -> MappingStatus {
+    match *find_char(c) {
+        Mapping::Valid => MappingStatus::Valid,
+        Mapping::Ignored => MappingStatus::Ignored,
+        Mapping::Mapped(ref slice) => MappingStatus::Mapped(decode_slice(slice)),
+        Mapping::Deviation(ref slice) => MappingStatus::Deviation(decode_slice(slice)),
+        Mapping::Disallowed => MappingStatus::Disallowed,
+        Mapping::DisallowedStd3Valid => MappingStatus::DisallowedStd3Valid,
+        Mapping::DisallowedStd3Mapped(ref slice) => {
+            MappingStatus::DisallowedStd3Mapped(decode_slice(slice))
+        }
+        Mapping::DisallowedIdna2008 => MappingStatus::DisallowedIdna2008,
+    }
+}
+
+/// The mapping of a deviation character in transitional processing,
+/// or None if `c` is not a deviation.
+///
+/// Domains with deviations, like `faß.de`, map differently with
+/// `Config::transitional_processing`. This is `map_status(c)` for `MappingStatus::Deviation`.
+pub fn deviation_mapping(c: char) -> Option<&'static str> {
+    match map_status(c) {
+        MappingStatus::Deviation(mapping) => Some(mapping),
+        _ => None,
+    }
+}
+
 struct Mapper<'a> {
     chars: std::str::Chars<'a>,
     config: Config,
-    errors: &'a mut Errors,
+    errors: Errors,
     slice: Option<std::str::Chars<'static>>,
+    label: usize,
 }
 
-impl<'a> Iterator for Mapper<'a> {
-    type Item = char;
+impl<'a> Mapper<'a> {
+    fn errors(&mut self) -> &mut Errors {
+        if self.errors.failed_label.is_none() {
+            // The text is filled in once the whole domain is normalized.
+            self.errors.failed_label = Some((self.label, String::new()));
+        }
+        &mut self.errors
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
+    fn new(chars: std::str::Chars<'a>, config: Config) -> Self {
+        Mapper {
+            chars,
+            config,
+            errors: Errors::default(),
+            slice: None,
+            label: 0,
+        }
+    }
+
+    fn map_next(&mut self) -> Option<char> {
         loop {
             if let Some(s) = &mut self.slice {
                 match s.next() {
@@ -103,6 +198,7 @@ impl<'a> Iterator for Mapper<'a> {
                     continue;
                 }
                 Mapping::Deviation(ref slice) => {
+                    self.errors.had_deviations = true;
                     if self.config.transitional_processing {
                         self.slice = Some(decode_slice(slice).chars());
                         continue;
@@ -111,25 +207,25 @@ impl<'a> Iterator for Mapper<'a> {
                     }
                 }
                 Mapping::Disallowed => {
-                    self.errors.disallowed_character = true;
+                    self.errors().disallowed_character = true;
                     codepoint
                 }
                 Mapping::DisallowedStd3Valid => {
-                    if self.config.use_std3_ascii_rules {
-                        self.errors.disallowed_by_std3_ascii_rules = true;
+                    if self.config.std3_disallows(codepoint) {
+                        self.errors().disallowed_by_std3_ascii_rules = true;
                     };
                     codepoint
                 }
                 Mapping::DisallowedStd3Mapped(ref slice) => {
-                    if self.config.use_std3_ascii_rules {
-                        self.errors.disallowed_mapped_in_std3 = true;
+                    if self.config.std3_disallows(codepoint) {
+                        self.errors().disallowed_mapped_in_std3 = true;
                     };
                     self.slice = Some(decode_slice(slice).chars());
                     continue;
                 }
                 Mapping::DisallowedIdna2008 => {
                     if self.config.use_idna_2008_rules {
-                        self.errors.disallowed_in_idna_2008 = true;
+                        self.errors().disallowed_in_idna_2008 = true;
                     }
                     codepoint
                 }
@@ -138,6 +234,18 @@ impl<'a> Iterator for Mapper<'a> {
     }
 }
 
+impl<'a> Iterator for Mapper<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.map_next()?;
+        if c == '.' {
+            self.label += 1;
+        }
+        Some(c)
+    }
+}
+
 // http://tools.ietf.org/html/rfc5893#section-2
 fn passes_bidi(label: &str, is_bidi_domain: bool) -> bool {
     // Rule 0: Bidi Rules apply to Bidi Domain Names: a name with at least one RTL label.  A label
@@ -267,6 +375,194 @@ fn passes_bidi(label: &str, is_bidi_domain: bool) -> bool {
     true
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum JoiningType {
+    Dual,
+    Left,
+    Right,
+    Transparent,
+    NonJoining,
+}
+
+fn joining_type(c: char) -> JoiningType {
+    match JOINING_TYPE_TABLE.binary_search_by(|&(first, last, _)| {
+        if c < first {
+            Ordering::Greater
+        } else if c > last {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        }
+    }) {
+        Ok(i) => JOINING_TYPE_TABLE[i].2,
+        Err(_) => JoiningType::NonJoining,
+    }
+}
+
+/// The ContextJ rules for ZERO WIDTH NON-JOINER and ZERO WIDTH JOINER
+///
+/// https://tools.ietf.org/html/rfc5892#appendix-A.1
+fn passes_contextj(label: &str) -> bool {
+    const VIRAMA: u8 = 9;
+    for (i, c) in label.char_indices() {
+        if c != '\u{200C}' && c != '\u{200D}' {
+            continue;
+        }
+        let (before, after) = (&label[..i], &label[i + c.len_utf8()..]);
+        if let Some(previous) = before.chars().next_back() {
+            if canonical_combining_class(previous) == VIRAMA {
+                continue;
+            }
+        }
+        if c == '\u{200D}' {
+            return false;
+        }
+
+        // (Joining_Type:{L,D})(Joining_Type:T)*\u200C(Joining_Type:T)*(Joining_Type:{R,D})
+        let mut before = before
+            .chars()
+            .rev()
+            .map(joining_type)
+            .skip_while(|&t| t == JoiningType::Transparent);
+        let mut after = after
+            .chars()
+            .map(joining_type)
+            .skip_while(|&t| t == JoiningType::Transparent);
+        let joins_before = matches!(
+            before.next(),
+            Some(JoiningType::Left) | Some(JoiningType::Dual)
+        );
+        let joins_after = matches!(
+            after.next(),
+            Some(JoiningType::Right) | Some(JoiningType::Dual)
+        );
+        if !joins_before || !joins_after {
+            return false;
+        }
+    }
+    true
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ContextOScript {
+    Greek,
+    Hebrew,
+    Hiragana,
+    Katakana,
+    Han,
+}
+
+/// The `Script` property of `c`, if it is one of those used by the ContextO rules.
+fn context_o_script(c: char) -> Option<ContextOScript> {
+    match CONTEXT_O_SCRIPT_TABLE.binary_search_by(|&(first, last, _)| {
+        if c < first {
+            Ordering::Greater
+        } else if c > last {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        }
+    }) {
+        Ok(i) => Some(CONTEXT_O_SCRIPT_TABLE[i].2),
+        Err(_) => None,
+    }
+}
+
+/// The ContextO rules for MIDDLE DOT, GREEK LOWER NUMERAL SIGN, HEBREW PUNCTUATION GERESH
+/// and GERSHAYIM, KATAKANA MIDDLE DOT, and ARABIC-INDIC DIGITS
+///
+/// https://tools.ietf.org/html/rfc5892#appendix-A.3
+fn passes_context_o(label: &str) -> bool {
+    let (mut arabic_indic_digits, mut extended_arabic_indic_digits) = (false, false);
+    for (i, c) in label.char_indices() {
+        let (before, after) = (&label[..i], &label[i + c.len_utf8()..]);
+        let passes = match c {
+            // Between two 'l', as in Catalan
+            '\u{B7}' => before.ends_with('l') && after.starts_with('l'),
+            '\u{375}' => {
+                after.chars().next().and_then(context_o_script) == Some(ContextOScript::Greek)
+            }
+            '\u{5F3}' | '\u{5F4}' => {
+                before.chars().next_back().and_then(context_o_script)
+                    == Some(ContextOScript::Hebrew)
+            }
+            '\u{30FB}' => label.chars().any(|c| {
+                matches!(
+                    context_o_script(c),
+                    Some(ContextOScript::Hiragana)
+                        | Some(ContextOScript::Katakana)
+                        | Some(ContextOScript::Han)
+                )
+            }),
+            '\u{660}'..='\u{669}' => {
+                arabic_indic_digits = true;
+                true
+            }
+            '\u{6F0}'..='\u{6F9}' => {
+                extended_arabic_indic_digits = true;
+                true
+            }
+            _ => true,
+        };
+        if !passes {
+            return false;
+        }
+    }
+    !(arabic_indic_digits && extended_arabic_indic_digits)
+}
+
+/// A set of scripts, with one bit per script of the Unicode Character
+/// Database and the augmented script sets of UTS #39.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct ScriptSet([u64; 3]);
+
+impl ScriptSet {
+    const ALL: ScriptSet = ScriptSet([!0; 3]);
+
+    fn intersection(self, other: ScriptSet) -> ScriptSet {
+        let (a, b) = (self.0, other.0);
+        ScriptSet([a[0] & b[0], a[1] & b[1], a[2] & b[2]])
+    }
+
+    fn is_empty(self) -> bool {
+        self.0 == [0; 3]
+    }
+}
+
+/// The augmented Script_Extensions of `c`, all scripts for Common and Inherited characters.
+fn script_set(c: char) -> ScriptSet {
+    match SCRIPT_TABLE.binary_search_by(|&(first, last, _)| {
+        if c < first {
+            Ordering::Greater
+        } else if c > last {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        }
+    }) {
+        Ok(i) => SCRIPT_SETS[SCRIPT_TABLE[i].2 as usize],
+        Err(_) => ScriptSet::ALL,
+    }
+}
+
+/// The script mixing part of the Moderately Restrictive profile of UTS #39
+///
+/// http://www.unicode.org/reports/tr39/#Restriction_Level_Detection
+fn passes_mixed_script(label: &str) -> bool {
+    let (mut all, mut not_latin) = (ScriptSet::ALL, ScriptSet::ALL);
+    for c in label.chars() {
+        let scripts = script_set(c);
+        all = all.intersection(scripts);
+        if scripts.intersection(LATIN).is_empty() {
+            not_latin = not_latin.intersection(scripts);
+        }
+    }
+    // Single script, including Latin + Han + Hiragana + Katakana and the like.
+    !all.is_empty()
+        // Latin and a single other Recommended script except Cyrillic and Greek.
+        || !not_latin.intersection(LATIN_COMPANIONS).is_empty()
+}
+
 /// Check the validity criteria for the given label
 ///
 /// V1 (NFC) and V8 (Bidi) are checked inside `processing()` to prevent doing duplicate work.
@@ -279,220 +575,457 @@ fn check_validity(label: &str, config: Config, errors: &mut Errors) {
         return;
     }
 
+    // Not in UTS #46, see `Config::max_label_code_points`
+    if config.max_label_code_points != punycode::USIZE_MAX
+        && label.chars().count() > config.max_label_code_points
+    {
+        errors.too_many_code_points = true;
+        if !config.collect_all_errors {
+            return;
+        }
+    }
+
     // V2: No U+002D HYPHEN-MINUS in both third and fourth positions.
     //
     // NOTE: Spec says that the label must not contain a HYPHEN-MINUS character in both the
     // third and fourth positions. But nobody follows this criteria. See the spec issue below:
     // https://github.com/whatwg/url/issues/53
+    // So it is only checked with `Config::check_hyphens_positional`.
+    if config.check_hyphens_positional {
+        let mut chars = label.chars().skip(2);
+        if chars.next() == Some('-') && chars.next() == Some('-') {
+            errors.check_hyphens = true;
+            if !config.collect_all_errors {
+                return;
+            }
+        }
+    }
 
     // V3: neither begin nor end with a U+002D HYPHEN-MINUS
     if config.check_hyphens && (label.starts_with('-') || label.ends_with('-')) {
         errors.check_hyphens = true;
-        return;
+        if !config.collect_all_errors {
+            return;
+        }
     }
 
     // V4: not contain a U+002E FULL STOP
     //
-    // Here, label can't contain '.' since the input is from .split('.')
+    // Here, label can't contain '.' since the input is from .split('.'),
+    // except for `Config::label_to_ascii` which leaves the splitting to the caller.
 
     // V5: not begin with a GC=Mark
     if is_combining_mark(first_char.unwrap()) {
         errors.start_combining_mark = true;
-        return;
+        if !config.collect_all_errors {
+            return;
+        }
     }
 
     // V6: Check against Mapping Table
     if label.chars().any(|c| match *find_char(c) {
         Mapping::Valid | Mapping::DisallowedIdna2008 => false,
         Mapping::Deviation(_) => config.transitional_processing,
-        Mapping::DisallowedStd3Valid => config.use_std3_ascii_rules,
+        Mapping::DisallowedStd3Valid => config.std3_disallows(c),
         _ => true,
     }) {
         errors.invalid_mapping = true;
     }
 
     // V7: ContextJ rules
-    //
-    // TODO: Implement rules and add *CheckJoiners* flag.
+    if config.check_joiners && !passes_contextj(label) {
+        errors.check_joiners = true;
+    }
+
+    // ContextO rules, which UTS #46 leaves out
+    if config.check_context_o && !passes_context_o(label) {
+        errors.check_context_o = true;
+    }
+
+    if config.restrict_mixed_script && !passes_mixed_script(label) {
+        errors.mixed_script = true;
+    }
 
     // V8: Bidi rules are checked inside `processing()`
 }
 
-/// http://www.unicode.org/reports/tr46/#Processing
-#[allow(clippy::manual_strip)] // introduced in 1.45, MSRV is 1.36
-fn processing(
-    domain: &str,
-    config: Config,
-    normalized: &mut String,
-    output: &mut String,
-) -> Errors {
-    // Weed out the simple cases: only allow all lowercase ASCII characters and digits where none
-    // of the labels start with PUNYCODE_PREFIX and labels don't start or end with hyphen.
-    let (mut prev, mut simple, mut puny_prefix) = ('?', !domain.is_empty(), 0);
+/// Weed out the simple cases: only allow all lowercase ASCII characters and digits where none
+/// of the labels start with PUNYCODE_PREFIX and labels don't start or end with hyphen.
+/// With `allow_uppercase`, uppercase ASCII characters are allowed too.
+///
+/// Such domains are only lowercased by `processing`.
+fn is_simple(domain: &str, allow_uppercase: bool) -> bool {
+    if domain.is_empty() {
+        return false;
+    }
+    let (mut prev, mut puny_prefix) = ('?', 0);
     for c in domain.chars() {
         if c == '.' {
             if prev == '-' {
-                simple = false;
-                break;
+                return false;
             }
             puny_prefix = 0;
             continue;
         } else if puny_prefix == 0 && c == '-' {
-            simple = false;
-            break;
+            return false;
         } else if puny_prefix < 5 {
-            if c == ['x', 'n', '-', '-'][puny_prefix] {
+            if c.to_ascii_lowercase() == ['x', 'n', '-', '-'][puny_prefix] {
                 puny_prefix += 1;
                 if puny_prefix == 4 {
-                    simple = false;
-                    break;
+                    return false;
                 }
             } else {
                 puny_prefix = 5;
             }
         }
-        if !c.is_ascii_lowercase() && !c.is_ascii_digit() {
-            simple = false;
-            break;
+        let allowed = match c {
+            'a'..='z' | '0'..='9' => true,
+            'A'..='Z' => allow_uppercase,
+            _ => false,
+        };
+        if !allowed {
+            return false;
         }
         prev = c;
     }
 
-    if simple {
+    true
+}
+
+/// UTF-8 percent-decode a domain, or return None if a `%` is not followed by
+/// two hexadecimal digits or the decoded bytes are not UTF-8.
+fn percent_decode(domain: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(domain.len());
+    let mut iter = domain.bytes();
+    while let Some(byte) = iter.next() {
+        if byte == b'%' {
+            let high = char::from(iter.next()?).to_digit(16)?;
+            let low = char::from(iter.next()?).to_digit(16)?;
+            bytes.push((high * 16 + low) as u8);
+        } else {
+            bytes.push(byte);
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+/// http://www.unicode.org/reports/tr46/#Processing
+///
+/// Punycode labels decoding to more than `max_code_points` are errors.
+fn processing(
+    domain: &str,
+    config: Config,
+    max_code_points: usize,
+    decoder: &mut punycode::Decoder,
+    normalized: &mut String,
+    output: &mut String,
+) -> Errors {
+    let decoded;
+    let mut invalid_percent_encoding = false;
+    let domain = if config.percent_decode_input && domain.contains('%') {
+        match percent_decode(domain) {
+            Some(domain) => {
+                decoded = domain;
+                &*decoded
+            }
+            None => {
+                invalid_percent_encoding = true;
+                domain
+            }
+        }
+    } else {
+        domain
+    };
+
+    if is_simple(domain, true) && config.simple_labels_fit_max_code_points(domain) {
+        let offset = output.len();
         output.push_str(domain);
+        output[offset..].make_ascii_lowercase();
         return Errors::default();
     }
 
     normalized.clear();
-    let mut errors = Errors::default();
     let offset = output.len();
 
-    let iter = Mapper {
-        chars: domain.chars(),
-        config,
-        errors: &mut errors,
-        slice: None,
-    };
-
-    normalized.extend(iter.nfc());
+    let mut iter = Mapper::new(domain.chars(), config);
+    if config.assume_nfc {
+        normalized.extend(iter.by_ref());
+    } else {
+        normalized.extend(iter.by_ref().nfc());
+    }
+    let mut errors = iter.errors;
+    errors.percent_decode = invalid_percent_encoding;
+    if let Some((index, ref mut text)) = errors.failed_label {
+        text.push_str(normalized.split('.').nth(index).unwrap_or(""));
+    }
 
-    let mut decoder = punycode::Decoder::default();
-    let non_transitional = config.transitional_processing(false);
-    let (mut first, mut has_bidi_labels) = (true, false);
-    for label in normalized.split('.') {
-        if !first {
+    let mut has_bidi_labels = false;
+    for (index, label) in normalized.split('.').enumerate() {
+        if index > 0 {
             output.push('.');
         }
-        first = false;
-        if label.starts_with(PUNYCODE_PREFIX) {
-            match decoder.decode(&label[PUNYCODE_PREFIX.len()..]) {
-                Ok(decode) => {
-                    let start = output.len();
-                    output.extend(decode);
-                    let decoded_label = &output[start..];
-
-                    if !has_bidi_labels {
-                        has_bidi_labels |= is_bidi_domain(decoded_label);
-                    }
+        process_label(
+            label,
+            config,
+            max_code_points,
+            decoder,
+            &mut has_bidi_labels,
+            &mut errors,
+            output,
+        );
+        errors.record_failed_label(index, label);
+    }
 
-                    if !errors.is_err() {
-                        if !is_nfc(&decoded_label) {
-                            errors.nfc = true;
-                        } else {
-                            check_validity(decoded_label, non_transitional, &mut errors);
-                        }
-                    }
+    if config.check_bidi {
+        for (index, label) in output[offset..].split('.').enumerate() {
+            // V8: Bidi rules
+            if !passes_bidi(label, has_bidi_labels) {
+                errors.check_bidi = true;
+                let label = normalized.split('.').nth(index).unwrap_or("");
+                errors.record_failed_label(index, label);
+                if !config.collect_all_errors {
+                    break;
                 }
-                Err(()) => {
-                    has_bidi_labels = true;
+            }
+        }
+    }
+
+    errors
+}
+
+/// Process a single label of `processing`, pushing it to `output`.
+#[allow(clippy::manual_strip)] // introduced in 1.45, MSRV is 1.36
+fn process_label(
+    label: &str,
+    config: Config,
+    max_code_points: usize,
+    decoder: &mut punycode::Decoder,
+    has_bidi_labels: &mut bool,
+    errors: &mut Errors,
+    output: &mut String,
+) {
+    let non_transitional = config.transitional_processing(false);
+    if label.starts_with(PUNYCODE_PREFIX) {
+        match decoder.decode_bounded(&label[PUNYCODE_PREFIX.len()..], max_code_points) {
+            Ok(decode) => {
+                let start = output.len();
+                output.extend(decode);
+                let decoded_label = &output[start..];
+
+                if config.strict_punycode
+                    && !punycode::is_canonical(
+                        decoded_label.chars(),
+                        &label[PUNYCODE_PREFIX.len()..],
+                    )
+                {
                     errors.punycode = true;
                 }
+
+                if config.check_bidi && !*has_bidi_labels {
+                    *has_bidi_labels |= is_bidi_domain(decoded_label);
+                }
+
+                if config.collect_all_errors || !errors.is_err() {
+                    if !is_nfc(&decoded_label) {
+                        errors.nfc = true;
+                    } else {
+                        check_validity(decoded_label, non_transitional, errors);
+                    }
+                }
             }
-        } else {
-            if !has_bidi_labels {
-                has_bidi_labels |= is_bidi_domain(label);
+            Err(punycode::PunycodeError::TooLong) => {
+                // Keep the label as-is, so that it fails the DNS length check
+                // and doesn't look like an empty label.
+                errors.too_long_for_dns = true;
+                output.push_str(label);
+            }
+            Err(_) if config.ignore_invalid_punycode => {
+                // Keep the label as-is, it is still checked like any other ASCII label.
+                check_validity(label, config, errors);
+                output.push_str(label);
+            }
+            Err(_) => {
+                *has_bidi_labels = true;
+                errors.punycode = true;
             }
-
-            // `normalized` is already `NFC` so we can skip that check
-            check_validity(label, config, &mut errors);
-            output.push_str(label)
         }
+    } else {
+        if config.check_bidi && !*has_bidi_labels {
+            *has_bidi_labels |= is_bidi_domain(label);
+        }
+
+        // `normalized` is already `NFC` so we can skip that check
+        check_validity(label, config, errors);
+        output.push_str(label)
     }
+}
 
-    for label in output[offset..].split('.') {
-        // V8: Bidi rules
-        //
-        // TODO: Add *CheckBidi* flag
-        if !passes_bidi(label, has_bidi_labels) {
-            errors.check_bidi = true;
-            break;
+/// Push the ASCII form of a processed label to `out`, returning `false` on overflow.
+fn encode_label(label: &str, out: &mut String) -> bool {
+    if label.is_ascii() {
+        out.push_str(label);
+        return true;
+    }
+    let offset = out.len();
+    out.push_str(PUNYCODE_PREFIX);
+    if punycode::encode_into(label.chars(), out).is_err() {
+        out.truncate(offset);
+        return false;
+    }
+    true
+}
+
+/// Encode each label of a processed domain to Punycode, then check the DNS length if enabled.
+fn encode_domain(domain: &str, config: Config, errors: &mut Errors, out: &mut String) {
+    for (index, label) in domain.split('.').enumerate() {
+        if index > 0 {
+            out.push('.');
+        }
+
+        if !encode_label(label, out) {
+            // Overflow only happens on labels that are too long for DNS anyway,
+            // keep the label rather than an empty one for `check_dns_length`
+            if config.verify_dns_length {
+                errors.too_long_for_dns = true;
+                out.push_str(label);
+            } else {
+                errors.punycode = true;
+            }
+            errors.record_failed_label(index, label);
         }
     }
 
-    errors
+    if config.verify_dns_length {
+        check_dns_length(out, config, errors);
+    }
 }
 
+#[allow(clippy::manual_strip)] // introduced in 1.45, MSRV is 1.36
+fn check_dns_length(domain: &str, config: Config, errors: &mut Errors) {
+    let domain = if domain.ends_with('.') {
+        &domain[..domain.len() - 1]
+    } else {
+        domain
+    };
+    if domain.len() > usize::from(config.max_domain_length) {
+        errors.too_long_for_dns = true;
+    }
+    for (index, label) in domain.split('.').enumerate() {
+        if label.is_empty() {
+            errors.too_short_for_dns = true;
+        } else if label.len() > usize::from(config.max_label_length) {
+            errors.too_long_for_dns = true;
+        } else {
+            continue;
+        }
+        errors.record_failed_label(index, label);
+    }
+}
+
+/// A reusable IDNA processor.
+///
+/// Unlike the methods of `Config`, `to_ascii` and `to_unicode` write to a string
+/// provided by the caller, and the intermediate buffers of an `Idna` are kept
+/// from one call to the next. Reusing the same `Idna` for many domains
+/// avoids most allocations once these buffers are large enough.
 #[derive(Default)]
 pub struct Idna {
     config: Config,
+    decoder: punycode::Decoder,
     normalized: String,
     output: String,
+    had_deviations: bool,
 }
 
 impl Idna {
     pub fn new(config: Config) -> Self {
+        Self::with_capacity(config, 0)
+    }
+
+    /// Create an `Idna` whose buffers can hold domains of `capacity` bytes
+    /// without reallocating.
+    pub fn with_capacity(config: Config, capacity: usize) -> Self {
         Self {
             config,
-            normalized: String::new(),
-            output: String::new(),
+            decoder: punycode::Decoder::default(),
+            normalized: String::with_capacity(capacity),
+            output: String::with_capacity(capacity),
+            had_deviations: false,
         }
     }
 
+    /// Whether the domain of the last call to `to_ascii` or `to_unicode` contained deviation
+    /// characters, see `Errors::had_deviations`.
+    ///
+    /// Unlike `Errors`, this is also available when the conversion succeeded.
+    pub fn had_deviations(&self) -> bool {
+        self.had_deviations
+    }
+
     /// http://www.unicode.org/reports/tr46/#ToASCII
+    ///
+    /// `out` is cleared first, then the labels are written to it,
+    /// Punycode-encoded as needed.
     #[allow(clippy::wrong_self_convention)]
     pub fn to_ascii<'a>(&'a mut self, domain: &str, out: &mut String) -> Result<(), Errors> {
-        let mut errors = processing(domain, self.config, &mut self.normalized, &mut self.output);
-
-        let mut first = true;
-        for label in self.output.split('.') {
-            if !first {
-                out.push('.');
-            }
-            first = false;
+        out.clear();
+        self.output.clear();
+        let mut errors = processing(
+            domain,
+            self.config,
+            self.config.max_code_points(),
+            &mut self.decoder,
+            &mut self.normalized,
+            &mut self.output,
+        );
+        self.had_deviations = errors.had_deviations;
+        encode_domain(&self.output, self.config, &mut errors, out);
+        errors.into()
+    }
 
-            if label.is_ascii() {
-                out.push_str(label);
-            } else {
-                let offset = out.len();
-                out.push_str(PUNYCODE_PREFIX);
-                if let Err(()) = punycode::encode_into(label.chars(), out) {
+    /// Check `domain` like `to_ascii` does, without building its ASCII form when possible.
+    ///
+    /// The Punycode encoding is skipped unless `verify_dns_length` is enabled,
+    /// since the DNS lengths are those of the encoded domain. Otherwise non-ASCII labels
+    /// are only checked to not overflow the encoder, with `punycode::encoded_len`.
+    pub fn validate(&mut self, domain: &str) -> Result<(), Errors> {
+        self.output.clear();
+        let mut errors = processing(
+            domain,
+            self.config,
+            self.config.max_code_points(),
+            &mut self.decoder,
+            &mut self.normalized,
+            &mut self.output,
+        );
+        self.had_deviations = errors.had_deviations;
+        if self.config.verify_dns_length {
+            let mut ascii = String::with_capacity(self.output.len());
+            encode_domain(&self.output, self.config, &mut errors, &mut ascii);
+        } else {
+            for (index, label) in self.output.split('.').enumerate() {
+                if !label.is_ascii() && punycode::encoded_len(label).is_none() {
                     errors.punycode = true;
-                    out.truncate(offset);
+                    errors.record_failed_label(index, label);
                 }
             }
         }
-
-        if self.config.verify_dns_length {
-            let domain = if out.ends_with('.') {
-                &out[..out.len() - 1]
-            } else {
-                &*out
-            };
-            if domain.is_empty() || domain.split('.').any(|label| label.is_empty()) {
-                errors.too_short_for_dns = true;
-            }
-            if domain.len() > 253 || domain.split('.').any(|label| label.len() > 63) {
-                errors.too_long_for_dns = true;
-            }
-        }
-
         errors.into()
     }
 
     /// http://www.unicode.org/reports/tr46/#ToUnicode
     #[allow(clippy::wrong_self_convention)]
     pub fn to_unicode<'a>(&'a mut self, domain: &str, out: &mut String) -> Result<(), Errors> {
-        processing(domain, self.config, &mut self.normalized, out).into()
+        let errors = processing(
+            domain,
+            self.config,
+            punycode::USIZE_MAX,
+            &mut self.decoder,
+            &mut self.normalized,
+            out,
+        );
+        self.had_deviations = errors.had_deviations;
+        errors.into()
     }
 }
 
@@ -503,6 +1036,20 @@ pub struct Config {
     verify_dns_length: bool,
     check_hyphens: bool,
     use_idna_2008_rules: bool,
+    strict_punycode: bool,
+    check_joiners: bool,
+    check_bidi: bool,
+    collect_all_errors: bool,
+    std3_allowed: Option<fn(char) -> bool>,
+    ignore_invalid_punycode: bool,
+    max_label_length: u8,
+    max_domain_length: u16,
+    restrict_mixed_script: bool,
+    check_context_o: bool,
+    check_hyphens_positional: bool,
+    assume_nfc: bool,
+    max_label_code_points: usize,
+    percent_decode_input: bool,
 }
 
 /// The defaults are that of https://url.spec.whatwg.org/#idna
@@ -512,17 +1059,75 @@ impl Default for Config {
             use_std3_ascii_rules: false,
             transitional_processing: false,
             check_hyphens: false,
-            // check_bidi: true,
-            // check_joiners: true,
+            check_bidi: true,
+            check_joiners: true,
 
             // Only use for to_ascii, not to_unicode
             verify_dns_length: false,
             use_idna_2008_rules: false,
+            strict_punycode: false,
+            collect_all_errors: false,
+            std3_allowed: None,
+            ignore_invalid_punycode: false,
+            max_label_length: 63,
+            max_domain_length: 253,
+            restrict_mixed_script: false,
+            check_context_o: false,
+            check_hyphens_positional: false,
+            assume_nfc: false,
+            max_label_code_points: punycode::USIZE_MAX,
+            percent_decode_input: false,
         }
     }
 }
 
 impl Config {
+    /// The Transitional Processing profile of UTS #46, as in its conformance tests:
+    /// deviations are mapped (`ß` to `ss`, `ς` to `σ`, ...), the STD3 ASCII rules apply,
+    /// hyphens are checked, and `to_ascii` verifies the DNS length.
+    ///
+    /// This is `Config::nontransitional().transitional_processing(true)`.
+    #[inline]
+    pub fn transitional() -> Self {
+        Config::nontransitional().transitional_processing(true)
+    }
+
+    /// The settings of the [host parser](https://url.spec.whatwg.org/#concept-domain-to-ascii)
+    /// of the URL Standard, used by the `url` crate through `domain_to_ascii`.
+    ///
+    /// This is nontransitional processing without the STD3 ASCII rules, hyphen checks
+    /// or DNS length verification, since `beStrict` is false, and with the bidi
+    /// and ContextJ checks. Invalid Punycode is an error. The checks that are extensions
+    /// of this crate, like `check_context_o` or `restrict_mixed_script`, are all disabled.
+    ///
+    /// These are also the defaults of `Config::default()`, spelled out here so that they
+    /// stay those of the URL Standard if the defaults ever change.
+    #[inline]
+    pub fn whatwg_url_host() -> Self {
+        Config::default()
+            .transitional_processing(false)
+            .use_std3_ascii_rules(false)
+            .check_hyphens(false)
+            .verify_dns_length(false)
+            .check_bidi(true)
+            .check_joiners(true)
+            .ignore_invalid_punycode(false)
+    }
+
+    /// The Nontransitional Processing profile of UTS #46, as in its conformance tests:
+    /// deviations are kept, the STD3 ASCII rules apply, hyphens are checked,
+    /// and `to_ascii` verifies the DNS length.
+    ///
+    /// Unlike `Config::default()`, which follows the URL Standard, this is for registries
+    /// and other uses of domain names outside of URLs.
+    #[inline]
+    pub fn nontransitional() -> Self {
+        Config::default()
+            .use_std3_ascii_rules(true)
+            .verify_dns_length(true)
+            .check_hyphens(true)
+    }
+
     #[inline]
     pub fn use_std3_ascii_rules(mut self, value: bool) -> Self {
         self.use_std3_ascii_rules = value;
@@ -547,12 +1152,179 @@ impl Config {
         self
     }
 
+    /// Reject labels with hyphens in both the third and fourth positions, like `ab--cd`,
+    /// as in the V2 validity criterion of UTS #46 that the URL Standard leaves out.
+    ///
+    /// `xn--` labels are checked once decoded, so valid ones pass.
+    /// This is reported as `ErrorKind::CheckHyphens`.
+    #[inline]
+    pub fn check_hyphens_positional(mut self, value: bool) -> Self {
+        self.check_hyphens_positional = value;
+        self
+    }
+
     #[inline]
     pub fn use_idna_2008_rules(mut self, value: bool) -> Self {
         self.use_idna_2008_rules = value;
         self
     }
 
+    /// Check the bidi rules of RFC 5893 for domains containing right-to-left characters.
+    #[inline]
+    pub fn check_bidi(mut self, value: bool) -> Self {
+        self.check_bidi = value;
+        self
+    }
+
+    /// Check the ContextJ rules for ZERO WIDTH JOINER and ZERO WIDTH NON-JOINER.
+    #[inline]
+    pub fn check_joiners(mut self, value: bool) -> Self {
+        self.check_joiners = value;
+        self
+    }
+
+    /// Check the ContextO rules of RFC 5892, which UTS #46 doesn't include.
+    ///
+    /// MIDDLE DOT must be between two `l`, GREEK LOWER NUMERAL SIGN must be followed by a
+    /// Greek character, HEBREW PUNCTUATION GERESH and GERSHAYIM must follow a Hebrew character,
+    /// KATAKANA MIDDLE DOT needs a Hiragana, Katakana or Han character in the label,
+    /// and ARABIC-INDIC DIGITS can't be mixed with EXTENDED ARABIC-INDIC DIGITS.
+    #[inline]
+    pub fn check_context_o(mut self, value: bool) -> Self {
+        self.check_context_o = value;
+        self
+    }
+
+    /// Reject `xn--` labels that are not the canonical encoding of their decoded form.
+    /// See `punycode::decode_strict`.
+    #[inline]
+    pub fn strict_punycode(mut self, value: bool) -> Self {
+        self.strict_punycode = value;
+        self
+    }
+
+    /// The maximum length in bytes of an ASCII label with `verify_dns_length`, 63 by default.
+    #[inline]
+    pub fn max_label_length(mut self, value: u8) -> Self {
+        self.max_label_length = value;
+        self
+    }
+
+    /// The maximum length in bytes of an ASCII domain, without its trailing dot,
+    /// with `verify_dns_length`, 253 by default.
+    #[inline]
+    pub fn max_domain_length(mut self, value: u16) -> Self {
+        self.max_domain_length = value;
+        self
+    }
+
+    /// Reject labels with more than `value` code points once mapped and normalized,
+    /// or once decoded for `xn--` labels, like some registries do.
+    ///
+    /// This is unlimited by default, and independent of the length in bytes of the ASCII
+    /// form checked with `verify_dns_length`.
+    /// This is reported as `ErrorKind::TooManyCodePoints`.
+    #[inline]
+    pub fn max_label_code_points(mut self, value: usize) -> Self {
+        self.max_label_code_points = value;
+        self
+    }
+
+    /// UTF-8 percent-decode the domain before mapping it, like the host parser
+    /// of the URL Standard does, so that `caf%C3%A9.com` is `café.com`.
+    ///
+    /// A `%` that is not followed by two hexadecimal digits, or percent-encoded bytes that
+    /// are not UTF-8, are reported as `ErrorKind::PercentDecode`, and the domain is then
+    /// processed as is. This applies to `to_ascii` and `to_unicode`, not to `label_to_ascii`.
+    #[inline]
+    pub fn percent_decode_input(mut self, value: bool) -> Self {
+        self.percent_decode_input = value;
+        self
+    }
+
+    /// Reject labels that mix scripts, as in the Moderately Restrictive profile of UTS #39.
+    ///
+    /// Each label must either be in a single script, or in Latin and a single other
+    /// script that is Recommended by UAX #31 and isn't Cyrillic or Greek.
+    /// Like in UTS #39, scripts are determined by `Script_Extensions`, Common and Inherited
+    /// characters go with any script, and Han goes with Hiragana and Katakana, with Hangul,
+    /// or with Bopomofo. The identifier profile part of UTS #39 is not checked.
+    #[inline]
+    pub fn restrict_mixed_script(mut self, value: bool) -> Self {
+        self.restrict_mixed_script = value;
+        self
+    }
+
+    /// Trust that the input is already in Unicode Normalization Form C, and skip normalizing
+    /// the mapped domain. Characters are still mapped, and `xn--` labels are still checked
+    /// to decode to NFC.
+    ///
+    /// **Use with care:** this is only correct when the input was normalized beforehand,
+    /// for instance because it is the output of a previous `to_unicode`.
+    /// Otherwise, the result can be wrong without any error being reported:
+    /// for instance `e\u{301}` would be converted to ASCII as decomposed `xn--e-xbb`
+    /// rather than as the `xn--9ca` of `\u{e9}`.
+    #[inline]
+    pub fn assume_nfc(mut self, value: bool) -> Self {
+        self.assume_nfc = value;
+        self
+    }
+
+    /// The Punycode labels decoding to more code points than this are errors.
+    /// Whether the labels of a domain for which `is_simple` is true are within
+    /// `max_label_code_points`: simple labels have as many code points as bytes.
+    fn simple_labels_fit_max_code_points(&self, domain: &str) -> bool {
+        self.max_label_code_points == punycode::USIZE_MAX
+            || domain
+                .split('.')
+                .all(|label| label.len() <= self.max_label_code_points)
+    }
+
+    fn max_code_points(&self) -> usize {
+        // Every code point takes at least one byte once encoded again,
+        // so longer labels would be rejected by the DNS length check anyway.
+        if self.verify_dns_length {
+            usize::from(self.max_label_length)
+        } else {
+            punycode::USIZE_MAX
+        }
+    }
+
+    /// Keep `xn--` labels that are not valid Punycode as-is instead of reporting an error,
+    /// like the `IgnoreInvalidPunycode` flag of UTS #46.
+    #[inline]
+    pub fn ignore_invalid_punycode(mut self, value: bool) -> Self {
+        self.ignore_invalid_punycode = value;
+        self
+    }
+
+    /// Allow the characters for which `allowed` returns `true` even though
+    /// `use_std3_ascii_rules` disallows them, like `_` in service labels.
+    ///
+    /// This has no effect unless `use_std3_ascii_rules` is enabled, and only applies to
+    /// characters that STD3 rules disallow: other disallowed characters are still errors.
+    /// Characters that STD3 rules disallow once mapped are still mapped.
+    #[inline]
+    pub fn std3_allowed(mut self, allowed: fn(char) -> bool) -> Self {
+        self.std3_allowed = Some(allowed);
+        self
+    }
+
+    fn std3_disallows(&self, c: char) -> bool {
+        match self.std3_allowed {
+            Some(allowed) => self.use_std3_ascii_rules && !allowed(c),
+            None => self.use_std3_ascii_rules,
+        }
+    }
+
+    /// Keep checking after the first failure, so that `Errors` records every kind of error
+    /// found in every label instead of only the first ones.
+    #[inline]
+    pub fn collect_all_errors(mut self, value: bool) -> Self {
+        self.collect_all_errors = value;
+        self
+    }
+
     /// http://www.unicode.org/reports/tr46/#ToASCII
     pub fn to_ascii(self, domain: &str) -> Result<String, Errors> {
         let mut result = String::new();
@@ -560,6 +1332,128 @@ impl Config {
         codec.to_ascii(domain, &mut result).map(|()| result)
     }
 
+    /// Check whether `domain` is valid, with the same result as `to_ascii` but without
+    /// building its ASCII form, for callers that only want to accept or reject it.
+    ///
+    /// The Punycode encoding is only done when `verify_dns_length` is enabled,
+    /// since the DNS length checks apply to the encoded domain.
+    /// Otherwise labels are only checked to be encodable, without allocating.
+    ///
+    /// ```rust
+    /// let config = idna::Config::default();
+    /// assert!(config.validate("bücher.example").is_ok());
+    /// assert!(config.validate("xn--a.example").is_err());
+    /// assert!(config.use_std3_ascii_rules(true).validate("a_b.example").is_err());
+    /// ```
+    pub fn validate(self, domain: &str) -> Result<(), Errors> {
+        Idna::new(self).validate(domain)
+    }
+
+    /// Like `to_ascii`, for a domain that was already mapped, normalized and checked,
+    /// for instance by an earlier stage of a pipeline.
+    ///
+    /// Only the Punycode encoding of non-ASCII labels, and the DNS length checks when
+    /// `verify_dns_length` is enabled, are done. **None of the other steps of `to_ascii` are:**
+    /// there is no mapping, so uppercase ASCII is kept as is, no normalization, no decoding
+    /// of `xn--` labels, and none of the validity criteria are checked.
+    ///
+    /// ```rust
+    /// let config = idna::Config::default();
+    /// assert_eq!(config.to_ascii_assume_mapped("bücher.example").unwrap(), "xn--bcher-kva.example");
+    /// // Not mapped, unlike with `to_ascii`.
+    /// assert_eq!(config.to_ascii_assume_mapped("Bücher").unwrap(), "xn--Bcher-kva");
+    /// ```
+    pub fn to_ascii_assume_mapped(self, already_mapped: &str) -> Result<String, Errors> {
+        let mut result = String::new();
+        let mut errors = Errors::default();
+        encode_domain(already_mapped, self, &mut errors, &mut result);
+        Result::from(errors).map(|()| result)
+    }
+
+    /// Like `to_ascii`, also returning the Unicode form of the domain computed on the way.
+    ///
+    /// That is the domain after mapping, normalization and decoding of its `xn--` labels,
+    /// which `to_ascii` then encodes to Punycode. It is returned even when there are errors.
+    pub fn to_ascii_and_unicode(self, domain: &str) -> (Result<String, Errors>, String) {
+        let mut result = String::new();
+        let mut codec = Idna::new(self);
+        let errors = codec.to_ascii(domain, &mut result);
+        (errors.map(|()| result), codec.output)
+    }
+
+    /// Like `to_ascii` for each domain, reusing the same buffers for all of them.
+    pub fn to_ascii_batch<'a, I>(self, domains: I) -> ToAsciiBatch<I::IntoIter>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        ToAsciiBatch {
+            codec: Idna::new(self),
+            domains: domains.into_iter(),
+        }
+    }
+
+    /// Like `to_ascii`, for a single label that is not split on dots.
+    ///
+    /// The label is still mapped, normalized and checked, including the bidi rules
+    /// as if it was the only label of a domain.
+    pub fn label_to_ascii(self, label: &str) -> Result<String, Errors> {
+        let mut mapper = Mapper::new(label.chars(), self);
+        let normalized = if self.assume_nfc {
+            mapper.by_ref().collect::<String>()
+        } else {
+            mapper.by_ref().nfc().collect::<String>()
+        };
+        let mut errors = mapper.errors;
+
+        let (mut output, mut has_bidi_labels) = (String::new(), false);
+        process_label(
+            &normalized,
+            self,
+            self.max_code_points(),
+            &mut punycode::Decoder::default(),
+            &mut has_bidi_labels,
+            &mut errors,
+            &mut output,
+        );
+        if self.check_bidi && !passes_bidi(&output, has_bidi_labels) {
+            errors.check_bidi = true;
+        }
+
+        let mut result = String::new();
+        let overflow = !encode_label(&output, &mut result);
+        if overflow && !self.verify_dns_length {
+            errors.punycode = true;
+        }
+        if self.verify_dns_length {
+            if overflow {
+                errors.too_long_for_dns = true;
+            } else if result.is_empty() {
+                errors.too_short_for_dns = true;
+            } else if result.len() > usize::from(self.max_label_length) {
+                errors.too_long_for_dns = true;
+            }
+        }
+
+        if errors.is_err() {
+            errors.failed_label = Some((0, normalized));
+        }
+        Result::from(errors).map(|()| result)
+    }
+
+    /// Like `to_ascii`, but borrow `domain` when it is already in its ASCII form,
+    /// which avoids an allocation for common domains like `example.com`.
+    pub fn to_ascii_cow(self, domain: &str) -> Result<Cow<'_, str>, Errors> {
+        if !is_simple(domain, false) || !self.simple_labels_fit_max_code_points(domain) {
+            return self.to_ascii(domain).map(Cow::Owned);
+        }
+
+        let mut errors = Errors::default();
+        if self.verify_dns_length {
+            check_dns_length(domain, self, &mut errors);
+        }
+        Result::from(errors).map(|()| Cow::Borrowed(domain))
+    }
+
     /// http://www.unicode.org/reports/tr46/#ToUnicode
     pub fn to_unicode(self, domain: &str) -> (String, Result<(), Errors>) {
         let mut codec = Idna::new(self);
@@ -567,9 +1461,71 @@ impl Config {
         let result = codec.to_unicode(domain, &mut out);
         (out, result)
     }
+
+    /// Return the result of the Processing step of UTS #46:
+    /// the domain after mapping, normalization to NFC and decoding of its `xn--` labels,
+    /// before any Punycode encoding.
+    ///
+    /// The errors are those of the validity and bidi checks on that string.
+    /// Unlike `to_unicode`, the output is guaranteed to be this intermediate form.
+    ///
+    /// http://www.unicode.org/reports/tr46/#Processing
+    pub fn to_unicode_mapped(self, domain: &str) -> (String, Result<(), Errors>) {
+        let mut normalized = String::new();
+        let mut out = String::with_capacity(domain.len());
+        let errors = processing(
+            domain,
+            self,
+            punycode::USIZE_MAX,
+            &mut punycode::Decoder::default(),
+            &mut normalized,
+            &mut out,
+        );
+        (out, errors.into())
+    }
+}
+
+/// An iterator over the results of `to_ascii` for several domains,
+/// see `Config::to_ascii_batch`.
+pub struct ToAsciiBatch<I> {
+    codec: Idna,
+    domains: I,
+}
+
+impl<'a, I> Iterator for ToAsciiBatch<I>
+where
+    I: Iterator<Item = &'a str>,
+{
+    type Item = Result<String, Errors>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let domain = self.domains.next()?;
+        let mut result = String::new();
+        Some(self.codec.to_ascii(domain, &mut result).map(|()| result))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.domains.size_hint()
+    }
 }
 
-fn is_bidi_domain(s: &str) -> bool {
+/// Whether a label passes the bidi rules of [RFC 5893](https://tools.ietf.org/html/rfc5893#section-2).
+///
+/// The rules only apply to labels of Bidi Domain Names, see `is_bidi_domain`:
+/// with `is_bidi_domain` false, this always returns true.
+/// `label` is checked as is, it should already be mapped, normalized and decoded from Punycode,
+/// like when `Config::check_bidi` checks the labels of a domain.
+pub fn passes_bidi_rules(label: &str, is_bidi_domain: bool) -> bool {
+    passes_bidi(label, is_bidi_domain)
+}
+
+/// Whether `s` contains a right-to-left character, of bidi class R, AL or AN.
+///
+/// Domains with such a label are Bidi Domain Names, whose labels must all pass
+/// the bidi rules of [RFC 5893](https://tools.ietf.org/html/rfc5893#section-2)
+/// when `Config::check_bidi` is set. This doesn't allocate, and considers `s`
+/// as is: Punycode labels are not decoded. Processing decodes them and checks them too.
+pub fn is_bidi_domain(s: &str) -> bool {
     for c in s.chars() {
         if c.is_ascii_graphic() {
             continue;
@@ -584,13 +1540,14 @@ fn is_bidi_domain(s: &str) -> bool {
 
 /// Errors recorded during UTS #46 processing.
 ///
-/// This is opaque for now, indicating what types of errors have been encountered at least once.
-/// More details may be exposed in the future.
+/// This indicates what kinds of errors have been encountered at least once,
+/// see `Errors::iter`.
 #[derive(Default)]
 pub struct Errors {
     punycode: bool,
     check_hyphens: bool,
     check_bidi: bool,
+    check_joiners: bool,
     start_combining_mark: bool,
     invalid_mapping: bool,
     nfc: bool,
@@ -600,14 +1557,52 @@ pub struct Errors {
     too_long_for_dns: bool,
     too_short_for_dns: bool,
     disallowed_in_idna_2008: bool,
+    mixed_script: bool,
+    check_context_o: bool,
+    too_many_code_points: bool,
+    percent_decode: bool,
+    had_deviations: bool,
+    failed_label: Option<(usize, String)>,
 }
 
 impl Errors {
     fn is_err(&self) -> bool {
+        self.iter().next().is_some()
+    }
+
+    fn record_failed_label(&mut self, index: usize, label: &str) {
+        if self.failed_label.is_none() && self.is_err() {
+            self.failed_label = Some((index, label.into()));
+        }
+    }
+
+    /// Return the zero-based index and the text of the first label that failed,
+    /// if the failure can be attributed to a single label.
+    ///
+    /// The text is that of the label after mapping and normalization, before any
+    /// Punycode decoding, or the ASCII label for the DNS length checks.
+    pub fn failed_label(&self) -> Option<(usize, String)> {
+        self.failed_label.clone()
+    }
+
+    /// Whether the domain contained deviation characters, `ß`, `ς`, ZERO WIDTH JOINER or
+    /// ZERO WIDTH NON-JOINER, whose processing differs between the transitional and
+    /// nontransitional profiles. See `deviation_mapping`.
+    ///
+    /// This is not an error and doesn't show in `iter`, so it is only available here
+    /// when the conversion failed for another reason: use `Idna::had_deviations` otherwise.
+    /// Only the input is considered, not the labels decoded from `xn--` ones.
+    pub fn had_deviations(&self) -> bool {
+        self.had_deviations
+    }
+
+    /// Return an iterator over the kinds of errors that were encountered.
+    pub fn iter(&self) -> ErrorKinds {
         let Errors {
             punycode,
             check_hyphens,
             check_bidi,
+            check_joiners,
             start_combining_mark,
             invalid_mapping,
             nfc,
@@ -617,67 +1612,168 @@ impl Errors {
             too_long_for_dns,
             too_short_for_dns,
             disallowed_in_idna_2008,
+            mixed_script,
+            check_context_o,
+            too_many_code_points,
+            percent_decode,
+            had_deviations: _,
+            failed_label: _,
         } = *self;
-        punycode
-            || check_hyphens
-            || check_bidi
-            || start_combining_mark
-            || invalid_mapping
-            || nfc
-            || disallowed_by_std3_ascii_rules
-            || disallowed_mapped_in_std3
-            || disallowed_character
-            || too_long_for_dns
-            || too_short_for_dns
-            || disallowed_in_idna_2008
+
+        ErrorKinds {
+            kinds: [
+                (ErrorKind::Punycode, punycode),
+                (ErrorKind::CheckHyphens, check_hyphens),
+                (ErrorKind::CheckBidi, check_bidi),
+                (ErrorKind::CheckJoiners, check_joiners),
+                (ErrorKind::StartCombiningMark, start_combining_mark),
+                (ErrorKind::InvalidMapping, invalid_mapping),
+                (ErrorKind::Nfc, nfc),
+                (
+                    ErrorKind::DisallowedByStd3AsciiRules,
+                    disallowed_by_std3_ascii_rules,
+                ),
+                (ErrorKind::DisallowedMappedInStd3, disallowed_mapped_in_std3),
+                (ErrorKind::DisallowedCharacter, disallowed_character),
+                (ErrorKind::TooLongForDns, too_long_for_dns),
+                (ErrorKind::TooShortForDns, too_short_for_dns),
+                (ErrorKind::DisallowedInIdna2008, disallowed_in_idna_2008),
+                (ErrorKind::MixedScript, mixed_script),
+                (ErrorKind::CheckContextO, check_context_o),
+                (ErrorKind::TooManyCodePoints, too_many_code_points),
+                (ErrorKind::PercentDecode, percent_decode),
+            ],
+            next: 0,
+        }
     }
 }
 
-impl fmt::Debug for Errors {
+/// A kind of error that can be recorded in `Errors`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(clippy::manual_non_exhaustive)] // introduced in 1.40, MSRV is 1.36
+pub enum ErrorKind {
+    /// An `xn--` label is not valid Punycode.
+    Punycode,
+    /// A label starts or ends with a hyphen, with `Config::check_hyphens`,
+    /// or has hyphens in both the third and fourth positions,
+    /// with `Config::check_hyphens_positional`.
+    CheckHyphens,
+    /// A label breaks the bidi rules.
+    CheckBidi,
+    /// A label breaks the ContextJ rules, with `Config::check_joiners`.
+    CheckJoiners,
+    /// A label starts with a combining mark.
+    StartCombiningMark,
+    /// A label contains a character that is not valid as-is, after mapping.
+    InvalidMapping,
+    /// A decoded `xn--` label is not in Normalization Form C.
+    Nfc,
+    /// A character is disallowed by `Config::use_std3_ascii_rules`.
+    DisallowedByStd3AsciiRules,
+    /// A character maps to characters disallowed by `Config::use_std3_ascii_rules`.
+    DisallowedMappedInStd3,
+    /// A character is disallowed.
+    DisallowedCharacter,
+    /// The domain or one of its labels is too long for DNS, with `Config::verify_dns_length`.
+    TooLongForDns,
+    /// The domain or one of its labels is empty, with `Config::verify_dns_length`.
+    TooShortForDns,
+    /// A character is disallowed by `Config::use_idna_2008_rules`.
+    DisallowedInIdna2008,
+    /// A label mixes scripts, with `Config::restrict_mixed_script`.
+    MixedScript,
+    /// A character breaks the ContextO rules, with `Config::check_context_o`.
+    CheckContextO,
+    /// A label has more code points than `Config::max_label_code_points`.
+    TooManyCodePoints,
+    /// The domain is not valid percent-encoded UTF-8, with `Config::percent_decode_input`.
+    PercentDecode,
+    /// Unused variant enable non-exhaustive matching
+    #[doc(hidden)]
+    __FutureProof,
+}
+
+impl ErrorKind {
+    fn name(self) -> &'static str {
+        match self {
+            ErrorKind::Punycode => "punycode",
+            ErrorKind::CheckHyphens => "check_hyphens",
+            ErrorKind::CheckBidi => "check_bidi",
+            ErrorKind::CheckJoiners => "check_joiners",
+            ErrorKind::StartCombiningMark => "start_combining_mark",
+            ErrorKind::InvalidMapping => "invalid_mapping",
+            ErrorKind::Nfc => "nfc",
+            ErrorKind::DisallowedByStd3AsciiRules => "disallowed_by_std3_ascii_rules",
+            ErrorKind::DisallowedMappedInStd3 => "disallowed_mapped_in_std3",
+            ErrorKind::DisallowedCharacter => "disallowed_character",
+            ErrorKind::TooLongForDns => "too_long_for_dns",
+            ErrorKind::TooShortForDns => "too_short_for_dns",
+            ErrorKind::DisallowedInIdna2008 => "disallowed_in_idna_2008",
+            ErrorKind::MixedScript => "mixed_script",
+            ErrorKind::CheckContextO => "check_context_o",
+            ErrorKind::TooManyCodePoints => "too_many_code_points",
+            ErrorKind::PercentDecode => "percent_decode",
+            ErrorKind::__FutureProof => unreachable!("Don't abuse the FutureProof!"),
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let Errors {
-            punycode,
-            check_hyphens,
-            check_bidi,
-            start_combining_mark,
-            invalid_mapping,
-            nfc,
-            disallowed_by_std3_ascii_rules,
-            disallowed_mapped_in_std3,
-            disallowed_character,
-            too_long_for_dns,
-            too_short_for_dns,
-            disallowed_in_idna_2008,
-        } = *self;
+        f.write_str(match *self {
+            ErrorKind::Punycode => "invalid punycode",
+            ErrorKind::CheckHyphens => "hyphen in an invalid position in a label",
+            ErrorKind::CheckBidi => "invalid mix of left-to-right and right-to-left text",
+            ErrorKind::CheckJoiners => "zero width joiner or non-joiner out of context",
+            ErrorKind::StartCombiningMark => "label starting with a combining mark",
+            ErrorKind::InvalidMapping => "character not valid in a label",
+            ErrorKind::Nfc => "punycode label not in normalization form C",
+            ErrorKind::DisallowedByStd3AsciiRules => "character disallowed by STD3 rules",
+            ErrorKind::DisallowedMappedInStd3 => "character mapped to one disallowed by STD3 rules",
+            ErrorKind::DisallowedCharacter => "disallowed character",
+            ErrorKind::TooLongForDns => "domain or label too long for DNS",
+            ErrorKind::TooShortForDns => "empty domain or label",
+            ErrorKind::DisallowedInIdna2008 => "character disallowed in IDNA 2008",
+            ErrorKind::MixedScript => "label mixing scripts",
+            ErrorKind::CheckContextO => "character out of context for the ContextO rules",
+            ErrorKind::TooManyCodePoints => "label with too many code points",
+            ErrorKind::PercentDecode => "invalid percent-encoded sequence",
+            ErrorKind::__FutureProof => unreachable!("Don't abuse the FutureProof!"),
+        })
+    }
+}
 
-        let fields = [
-            ("punycode", punycode),
-            ("check_hyphens", check_hyphens),
-            ("check_bidi", check_bidi),
-            ("start_combining_mark", start_combining_mark),
-            ("invalid_mapping", invalid_mapping),
-            ("nfc", nfc),
-            (
-                "disallowed_by_std3_ascii_rules",
-                disallowed_by_std3_ascii_rules,
-            ),
-            ("disallowed_mapped_in_std3", disallowed_mapped_in_std3),
-            ("disallowed_character", disallowed_character),
-            ("too_long_for_dns", too_long_for_dns),
-            ("too_short_for_dns", too_short_for_dns),
-            ("disallowed_in_idna_2008", disallowed_in_idna_2008),
-        ];
+/// An iterator over the kinds of errors in `Errors`, see `Errors::iter`.
+#[derive(Clone)]
+pub struct ErrorKinds {
+    kinds: [(ErrorKind, bool); 17],
+    next: usize,
+}
 
+impl Iterator for ErrorKinds {
+    type Item = ErrorKind;
+
+    fn next(&mut self) -> Option<ErrorKind> {
+        while let Some(&(kind, found)) = self.kinds.get(self.next) {
+            self.next += 1;
+            if found {
+                return Some(kind);
+            }
+        }
+        None
+    }
+}
+
+impl fmt::Debug for Errors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut empty = true;
         f.write_str("Errors { ")?;
-        for (name, val) in &fields {
-            if *val {
-                if !empty {
-                    f.write_str(", ")?;
-                }
-                f.write_str(*name)?;
-                empty = false;
+        for kind in self.iter() {
+            if !empty {
+                f.write_str(", ")?;
             }
+            f.write_str(kind.name())?;
+            empty = false;
         }
 
         if !empty {
@@ -702,7 +1798,88 @@ impl StdError for Errors {}
 
 impl fmt::Display for Errors {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Debug::fmt(self, f)
+        let mut kinds = self.iter();
+        match kinds.next() {
+            Some(kind) => write!(f, "{}", kind)?,
+            None => return f.write_str("no errors"),
+        }
+        for kind in kinds {
+            write!(f, ", {}", kind)?;
+        }
+        Ok(())
+    }
+}
+
+/// Serializes the kinds of errors into a `serde` stream, as a sequence of names
+/// like `["punycode", "check_bidi"]`.
+///
+/// This implementation is only available if the `serde` Cargo feature is enabled.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Errors {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.iter().map(ErrorKind::name))
+    }
+}
+
+/// Deserializes the kinds of errors from a `serde` stream.
+///
+/// This implementation is only available if the `serde` Cargo feature is enabled.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Errors {
+    fn deserialize<D>(deserializer: D) -> Result<Errors, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+        use std::vec::Vec;
+
+        const NAMES: &[&str] = &[
+            "punycode",
+            "check_hyphens",
+            "check_bidi",
+            "check_joiners",
+            "start_combining_mark",
+            "invalid_mapping",
+            "nfc",
+            "disallowed_by_std3_ascii_rules",
+            "disallowed_mapped_in_std3",
+            "disallowed_character",
+            "too_long_for_dns",
+            "too_short_for_dns",
+            "disallowed_in_idna_2008",
+            "mixed_script",
+            "check_context_o",
+            "too_many_code_points",
+            "percent_decode",
+        ];
+
+        let mut errors = Errors::default();
+        for name in Vec::<String>::deserialize(deserializer)? {
+            *match &*name {
+                "punycode" => &mut errors.punycode,
+                "check_hyphens" => &mut errors.check_hyphens,
+                "check_bidi" => &mut errors.check_bidi,
+                "check_joiners" => &mut errors.check_joiners,
+                "start_combining_mark" => &mut errors.start_combining_mark,
+                "invalid_mapping" => &mut errors.invalid_mapping,
+                "nfc" => &mut errors.nfc,
+                "disallowed_by_std3_ascii_rules" => &mut errors.disallowed_by_std3_ascii_rules,
+                "disallowed_mapped_in_std3" => &mut errors.disallowed_mapped_in_std3,
+                "disallowed_character" => &mut errors.disallowed_character,
+                "too_long_for_dns" => &mut errors.too_long_for_dns,
+                "too_short_for_dns" => &mut errors.too_short_for_dns,
+                "disallowed_in_idna_2008" => &mut errors.disallowed_in_idna_2008,
+                "mixed_script" => &mut errors.mixed_script,
+                "check_context_o" => &mut errors.check_context_o,
+                "too_many_code_points" => &mut errors.too_many_code_points,
+                "percent_decode" => &mut errors.percent_decode,
+                _ => return Err(D::Error::unknown_variant(&name, NAMES)),
+            } = true;
+        }
+        Ok(errors)
     }
 }
 
@@ -8,6 +8,9 @@
 
 // Generated by make_idna_table.py
 
+/// The version of Unicode of the UTS #46 mapping table, as `(major, minor, update)`.
+pub const UNICODE_VERSION: (u8, u8, u8) = (13, 0, 0);
+
 static TABLE: &[(char, u16)] = &[
     ('\u{0}', 32768),
     ('\u{2d}', 32769),
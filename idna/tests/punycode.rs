@@ -7,7 +7,7 @@
 // except according to those terms.
 
 use crate::test::TestFn;
-use idna::punycode::{decode, encode_str};
+use idna::punycode::{decode, encode_into, encode_str};
 use serde_json::map::Map;
 use serde_json::Value;
 use std::str::FromStr;
@@ -37,6 +37,18 @@ fn one_test(decoded: &str, encoded: &str) {
             encoded
         ),
     }
+
+    let mut buf = String::from("xn--");
+    match encode_into(decoded.chars(), &mut buf) {
+        Err(e) => panic!("Encoding {} failed: {}", decoded, e),
+        Ok(()) => assert!(
+            buf[4..] == *encoded,
+            "Incorrect encoding of \"{}\" into a buffer:\n   \"{}\"\n!= \"{}\"\n",
+            decoded,
+            &buf[4..],
+            encoded
+        ),
+    }
 }
 
 fn get_string<'a>(map: &'a Map<String, Value>, key: &str) -> &'a str {
@@ -113,6 +113,25 @@ fn test_v8_bidi_rules() {
 
     // Bidi chars may be punycode-encoded
     assert!(config.to_ascii("xn--0ca24w").is_err());
+
+    let config = config.check_bidi(false);
+    assert!(config.to_ascii("0a.\u{05D0}").is_ok());
+    assert!(config.to_ascii("xn--0ca24w").is_ok());
+}
+
+#[test]
+fn passes_bidi_rules() {
+    use idna::passes_bidi_rules;
+
+    assert!(passes_bidi_rules("0a", false));
+    assert!(!passes_bidi_rules("0a", true));
+    assert!(!passes_bidi_rules("0à", true));
+    assert!(passes_bidi_rules("a0", true));
+    assert!(passes_bidi_rules("\u{5d0}\u{5d1}", true));
+    assert!(passes_bidi_rules("\u{5d0}1", true));
+    assert!(!passes_bidi_rules("\u{5d0}a", true));
+    assert!(!passes_bidi_rules("a\u{5d0}", true));
+    assert!(passes_bidi_rules("", true));
 }
 
 #[test]
@@ -137,3 +156,1071 @@ fn unicode_before_delimiter() {
     let config = idna::Config::default();
     assert!(config.to_ascii("xn--f\u{34a}-PTP").is_err());
 }
+
+#[test]
+fn punycode_encode_into_overflow() {
+    let input: String = "a".repeat(5000) + "\u{10FFFF}";
+    let mut buf = String::new();
+    assert_eq!(
+        idna::punycode::encode_into(input.chars(), &mut buf),
+        Err(idna::punycode::EncodeError::Overflow)
+    );
+    assert_eq!(idna::punycode::encode_str(&input), None);
+}
+
+#[test]
+fn punycode_encoder() {
+    use idna::punycode::{encode_str, EncodeError, Encoder};
+
+    let mut encoder = Encoder::new();
+    assert_eq!(encoder.finish().unwrap(), "");
+    for input in &["abc", "ü", "bücher", "例え", "\u{10ffff}a"] {
+        encoder.extend(input.chars());
+        assert_eq!(encoder.finish().ok(), encode_str(input), "{}", input);
+    }
+
+    for c in "a".repeat(30000).chars().chain(Some('\u{30000}')) {
+        encoder.push(c);
+    }
+    assert_eq!(encoder.finish(), Err(EncodeError::Overflow));
+    encoder.push('ü');
+    assert_eq!(encoder.finish().unwrap(), "tda");
+}
+
+#[test]
+fn punycode_decode_parts() {
+    use idna::punycode::{decode, decode_parts};
+
+    for input in &[
+        "bcher-kva",
+        "abc-",
+        "tda",
+        "a-b-c-6ya4bb",
+        "mgba3a3ejt",
+        "-> $1.00 <--",
+    ] {
+        let (basic, insertions) = decode_parts(input).unwrap();
+        let mut merged: Vec<char> = basic.chars().collect();
+        for &(index, c) in &insertions {
+            merged.insert(index, c);
+        }
+        assert_eq!(Some(merged), decode(input), "{}", input);
+        assert!(insertions.iter().all(|&(_, c)| !c.is_ascii()));
+        assert!(insertions.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+    assert_eq!(decode_parts("abc-").unwrap(), ("abc".to_owned(), vec![]));
+    assert_eq!(
+        decode_parts("tda").unwrap(),
+        (String::new(), vec![(0, 'ü')])
+    );
+    assert_eq!(decode_parts("bcher-kva!"), None);
+    assert_eq!(decode_parts("ü-tda"), None);
+}
+
+#[test]
+fn punycode_encoded_len() {
+    use idna::punycode::{encode_str, encoded_len};
+
+    for input in &["", "abc", "bücher", "ü", "\u{10FFFF}", "ليهمابتكلموشعربي؟"] {
+        assert_eq!(encoded_len(input), encode_str(input).map(|s| s.len()));
+    }
+    assert_eq!(encoded_len("bücher"), Some("bcher-kva".len()));
+    assert_eq!(encoded_len(&("a".repeat(5000) + "\u{10FFFF}")), None);
+}
+
+#[test]
+fn punycode_encode_checked() {
+    use idna::punycode::{encode_checked, EncodeError, PUNYCODE_MAX_LABEL_BYTES};
+
+    let input: Vec<char> = "bücher".chars().collect();
+    assert_eq!(encode_checked(&input).unwrap(), "bcher-kva");
+
+    let input: Vec<char> = "a".repeat(55).chars().chain(Some('ü')).collect();
+    let encoded = encode_checked(&input).unwrap();
+    assert_eq!("xn--".len() + encoded.len(), PUNYCODE_MAX_LABEL_BYTES);
+    let input: Vec<char> = "a".repeat(56).chars().chain(Some('ü')).collect();
+    assert_eq!(encode_checked(&input), Err(EncodeError::TooLong));
+
+    let input: Vec<char> = "a".repeat(5000).chars().chain(Some('\u{10FFFF}')).collect();
+    assert_eq!(encode_checked(&input), Err(EncodeError::Overflow));
+
+    let domain = "a".repeat(30000) + "\u{30000}";
+    let errors = idna::Config::default().to_ascii(&domain).unwrap_err();
+    assert_eq!(
+        errors.iter().collect::<Vec<_>>(),
+        [idna::ErrorKind::Punycode]
+    );
+    let config = idna::Config::default().verify_dns_length(true);
+    let errors = config.to_ascii(&domain).unwrap_err();
+    assert_eq!(
+        errors.iter().collect::<Vec<_>>(),
+        [idna::ErrorKind::TooLongForDns]
+    );
+}
+
+#[test]
+fn punycode_decode_into_reuses_buffer() {
+    let mut buf = Vec::with_capacity(16);
+    let ptr = buf.as_ptr();
+    buf.extend(vec!['x'; 16]);
+    idna::punycode::decode_into("53h", &mut buf).unwrap();
+    assert_eq!(buf, ['☕']);
+    idna::punycode::decode_into("bcher-kva", &mut buf).unwrap();
+    assert_eq!(buf.iter().collect::<String>(), "bücher");
+    assert!(idna::punycode::decode_into("bcher-kva!", &mut buf).is_err());
+    assert!(buf.is_empty());
+    assert_eq!(buf.capacity(), 16);
+    assert_eq!(buf.as_ptr(), ptr);
+}
+
+#[test]
+fn punycode_decode_errors() {
+    use idna::punycode::{decode_into, PunycodeError};
+
+    let mut buf = Vec::new();
+    assert_eq!(
+        decode_into("bcher-kva!", &mut buf),
+        Err(PunycodeError::InvalidDigit(b'!'))
+    );
+    assert_eq!(
+        decode_into("bcher-kv", &mut buf),
+        Err(PunycodeError::UnexpectedEnd)
+    );
+    assert_eq!(
+        decode_into("b\u{fc}cher-kva", &mut buf),
+        Err(PunycodeError::InvalidCodePoint(0xFC))
+    );
+    assert_eq!(
+        decode_into("99999999999", &mut buf),
+        Err(PunycodeError::Overflow)
+    );
+    assert_eq!(idna::punycode::decode("bcher-kv"), None);
+}
+
+#[test]
+fn punycode_decode_strict() {
+    use idna::punycode::{decode, decode_strict};
+
+    assert_eq!(decode_strict("bcher-kva"), decode("bcher-kva"));
+    assert_eq!(decode_strict("53h"), decode("53h"));
+    assert_eq!(decode("bcher-KVA"), decode("bcher-kva"));
+    assert_eq!(decode_strict("bcher-KVA"), None);
+    assert_eq!(decode_strict("BCHER-kva"), decode("BCHER-kva"));
+
+    // The UTS #46 mapping lowercases labels before they are decoded.
+    let config = idna::Config::default();
+    let (unicode, result) = config.strict_punycode(true).to_unicode("xn--BCHER-KVA");
+    assert_eq!(unicode, "bücher");
+    assert!(result.is_ok());
+    assert_eq!(
+        config
+            .strict_punycode(true)
+            .to_ascii("xn--bcher-kva")
+            .unwrap(),
+        "xn--bcher-kva"
+    );
+}
+
+#[test]
+fn punycode_bootstring_params() {
+    use idna::punycode::{decode_with, encode_with, BootstringParams, Punycode};
+
+    let input: Vec<char> = "bücher".chars().collect();
+    let params = Punycode::DEFAULT;
+    assert!(params.is_valid());
+    assert_eq!(params, BootstringParams::default());
+    assert_eq!(encode_with(&params, &input).unwrap(), "bcher-kva");
+    assert_eq!(decode_with(&params, "bcher-kva").unwrap(), input);
+
+    let params = BootstringParams {
+        base: 30,
+        tmin: 2,
+        tmax: 20,
+        skew: 30,
+        damp: 500,
+        initial_bias: 50,
+        initial_n: 0x61,
+    };
+    let encoded = encode_with(&params, &input).unwrap();
+    assert_ne!(encoded, "bcher-kva");
+    assert!(!encoded.contains(|c| ('4'..='9').contains(&c)));
+    assert_eq!(decode_with(&params, &encoded).unwrap(), input);
+    assert_eq!(decode_with(&params, "bcher-kv9"), None);
+
+    let invalid = BootstringParams {
+        base: 37,
+        ..Punycode::DEFAULT
+    };
+    assert!(!invalid.is_valid());
+    assert_eq!(encode_with(&invalid, &input), None);
+    assert_eq!(decode_with(&invalid, "bcher-kva"), None);
+
+    // Large biases and skews don't overflow.
+    let large = BootstringParams {
+        initial_bias: !0 - 2,
+        skew: !0,
+        ..Punycode::DEFAULT
+    };
+    assert!(large.is_valid());
+    let encoded = encode_with(&large, &input).unwrap();
+    assert_eq!(decode_with(&large, &encoded).unwrap(), input);
+}
+
+#[test]
+fn punycode_decode_iter() {
+    use idna::punycode::{decode_iter, PunycodeError};
+
+    let decoded: Result<String, _> = decode_iter("bcher-kva").collect();
+    assert_eq!(decoded.unwrap(), "bücher");
+    let decoded: Result<String, _> = decode_iter("ihqwcrb4cv8a8dqg056pqjye").collect();
+    assert_eq!(decoded.unwrap(), "他们为什么不说中文");
+
+    let mut iter = decode_iter("bcher-kva");
+    assert_eq!(iter.size_hint(), (0, Some(9)));
+    assert_eq!(iter.next(), Some(Ok('b')));
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+    assert_eq!(iter.by_ref().count(), 5);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+
+    let mut iter = decode_iter("bcher-kv");
+    assert_eq!(iter.next(), Some(Err(PunycodeError::UnexpectedEnd)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn punycode_decode_bounded() {
+    use idna::punycode::{decode, decode_bounded, encode_str};
+
+    assert_eq!(decode_bounded("bcher-kva", 6), decode("bcher-kva"));
+    assert_eq!(decode_bounded("bcher-kva", 5), None);
+    assert_eq!(decode_bounded("bcher-kva", 4), None);
+
+    let long = encode_str(&"ü".repeat(64)).unwrap();
+    assert!(decode_bounded(&long, 64).is_some());
+    assert_eq!(decode_bounded(&long, 63), None);
+
+    let config = idna::Config::default().verify_dns_length(true);
+    let label = format!("xn--{}", long);
+    assert!(idna::Config::default().to_ascii(&label).is_ok());
+    let error = format!("{:?}", config.to_ascii(&label).unwrap_err());
+    assert_eq!(error, "Errors { too_long_for_dns }");
+    let error = format!("{:?}", config.to_ascii(&(label + ".com")).unwrap_err());
+    assert_eq!(error, "Errors { too_long_for_dns }");
+
+    let label = format!("xn--{}-", "a".repeat(64));
+    let error = format!("{:?}", config.to_ascii(&label).unwrap_err());
+    assert_eq!(error, "Errors { too_long_for_dns }");
+}
+
+#[test]
+fn punycode_insertion_order() {
+    use idna::punycode::{decode_to_string, encode_str};
+
+    assert_eq!(decode_to_string("4gqb").unwrap(), "丁一");
+    for input in &["abéè", "èéab", "aébèc", "一丁", "丁一", "ü1ü2ü3"] {
+        let encoded = encode_str(input).unwrap();
+        assert_eq!(decode_to_string(&encoded).unwrap(), *input);
+    }
+    for len in 0..200 {
+        // Mix insertions before and after earlier ones.
+        let input: String = (0..len)
+            .map(|i| match i % 3 {
+                0 => 'a',
+                1 => std::char::from_u32(0x4E00 + (i * 7919) % 512).unwrap(),
+                _ => std::char::from_u32(0xE0 + (len - i) % 16).unwrap(),
+            })
+            .collect();
+        let encoded = encode_str(&input).unwrap();
+        assert_eq!(decode_to_string(&encoded).unwrap(), input);
+    }
+}
+
+#[test]
+fn test_contextj_rules() {
+    let config = idna::Config::default();
+
+    // ZWNJ between two dual-joining characters
+    assert!(config.to_ascii("\u{0628}\u{200C}\u{0628}").is_ok());
+    // ... possibly with transparent characters around it
+    assert!(config
+        .to_ascii("\u{0628}\u{064B}\u{200C}\u{064B}\u{0628}")
+        .is_ok());
+    // ZWNJ and ZWJ after a virama
+    assert!(config.to_ascii("\u{0915}\u{094D}\u{200C}").is_ok());
+    assert!(config.to_ascii("\u{0915}\u{094D}\u{200D}").is_ok());
+
+    // Stray joiners
+    let error = format!("{:?}", config.to_ascii("a\u{200C}b").unwrap_err());
+    assert!(error.contains("check_joiners"));
+    assert!(config.to_ascii("a\u{200D}b").is_err());
+    assert!(config.to_ascii("\u{200C}\u{0628}").is_err());
+    assert!(config.to_ascii("\u{0628}\u{200D}\u{0628}").is_err());
+    // Punycode-encoded labels are checked too
+    assert!(config.to_ascii("xn--ab-j1t").is_err());
+
+    let config = config.check_joiners(false);
+    assert!(config.to_ascii("a\u{200C}b").is_ok());
+    // Transitional processing removes joiners before they are checked
+    let config = idna::Config::default().transitional_processing(true);
+    assert_eq!(config.to_ascii("a\u{200D}b").unwrap(), "ab");
+}
+
+#[test]
+fn error_kinds() {
+    use idna::ErrorKind;
+
+    let config = idna::Config::default()
+        .verify_dns_length(true)
+        .use_std3_ascii_rules(true);
+    let errors = config.to_ascii("a_b..com").unwrap_err();
+    assert_eq!(
+        errors.iter().collect::<Vec<_>>(),
+        [
+            ErrorKind::InvalidMapping,
+            ErrorKind::DisallowedByStd3AsciiRules,
+            ErrorKind::TooShortForDns
+        ]
+    );
+    assert_eq!(
+        errors.to_string(),
+        "character not valid in a label, character disallowed by STD3 rules, \
+         empty domain or label"
+    );
+    assert_eq!(
+        format!("{:?}", errors),
+        "Errors { invalid_mapping, disallowed_by_std3_ascii_rules, too_short_for_dns }"
+    );
+}
+
+#[test]
+fn failed_label() {
+    let config = idna::Config::default().verify_dns_length(true);
+    let errors = config.to_ascii("good.bâd..evil").unwrap_err();
+    assert_eq!(errors.failed_label(), Some((2, String::new())));
+
+    let config = idna::Config::default().use_std3_ascii_rules(true);
+    let errors = config.to_ascii("good.B_D.evil").unwrap_err();
+    assert_eq!(errors.failed_label(), Some((1, "b_d".to_owned())));
+
+    let errors = config.to_ascii("good.xn--a-99999999999.evil").unwrap_err();
+    assert_eq!(
+        errors.failed_label(),
+        Some((1, "xn--a-99999999999".to_owned()))
+    );
+
+    let errors = config.to_ascii("good.\u{5d0}a.evil").unwrap_err();
+    assert_eq!(errors.failed_label(), Some((1, "\u{5d0}a".to_owned())));
+
+    let config = idna::Config::default().verify_dns_length(true);
+    let errors = config.to_ascii(&("a.".repeat(127) + "a")).unwrap_err();
+    assert_eq!(errors.failed_label(), None);
+}
+
+#[test]
+fn to_ascii_cow() {
+    use std::borrow::Cow;
+
+    let config = idna::Config::default();
+    assert_matches!(
+        config.to_ascii_cow("example.com"),
+        Ok(Cow::Borrowed("example.com"))
+    );
+    for domain in &["Example.com", "bücher.de", "xn--bcher-kva.de", "a--b.c", ""] {
+        let cow = config.to_ascii_cow(domain);
+        assert_matches!(cow, Ok(Cow::Owned(_)));
+        assert_eq!(cow.ok(), config.to_ascii(domain).ok().map(Cow::Owned));
+    }
+
+    let config = idna::Config::default().verify_dns_length(true);
+    assert_matches!(config.to_ascii_cow("a.b."), Ok(Cow::Borrowed("a.b.")));
+    let errors = config.to_ascii_cow("a..b").unwrap_err();
+    assert_eq!(format!("{:?}", errors), "Errors { too_short_for_dns }");
+    assert_eq!(errors.failed_label(), Some((1, String::new())));
+}
+
+#[test]
+fn label_to_ascii() {
+    let config = idna::Config::default();
+    assert_eq!(config.label_to_ascii("Bücher").unwrap(), "xn--bcher-kva");
+    assert_eq!(config.label_to_ascii("a.b").unwrap(), "a.b");
+    assert_eq!(config.label_to_ascii("ö.ß").unwrap(), "xn--.-qfa1e");
+    assert_eq!(
+        config.label_to_ascii("xn--bcher-kva").unwrap(),
+        "xn--bcher-kva"
+    );
+
+    let errors = config.label_to_ascii("\u{5d0}a").unwrap_err();
+    assert_eq!(format!("{:?}", errors), "Errors { check_bidi }");
+    assert_eq!(errors.failed_label(), Some((0, "\u{5d0}a".to_owned())));
+
+    let errors = config.label_to_ascii("\u{301}a").unwrap_err();
+    assert_eq!(format!("{:?}", errors), "Errors { start_combining_mark }");
+
+    let config = config.verify_dns_length(true);
+    assert!(config.label_to_ascii("").is_err());
+    assert!(config.label_to_ascii(&"a".repeat(64)).is_err());
+    assert!(config.label_to_ascii(&"a".repeat(63)).is_ok());
+}
+
+#[test]
+fn to_unicode_mapped() {
+    let config = idna::Config::default();
+    let (mapped, result) = config.to_unicode_mapped("Bücher.xn--caf-dma.Ⅻ");
+    assert_eq!(mapped, "bücher.café.xii");
+    assert!(result.is_ok());
+
+    let (mapped, result) = config.to_unicode_mapped("a.\u{5d0}b");
+    assert_eq!(mapped, "a.\u{5d0}b");
+    assert_eq!(
+        format!("{:?}", result.unwrap_err()),
+        "Errors { check_bidi }"
+    );
+}
+
+#[test]
+fn collect_all_errors() {
+    use idna::ErrorKind;
+
+    let config = idna::Config::default().check_hyphens(true);
+    let domain = format!(
+        "-\u{301}.xn--{}.a",
+        idna::punycode::encode_str("\u{300}b").unwrap()
+    );
+    let errors = config.to_unicode(&domain).1.unwrap_err();
+    assert_eq!(errors.iter().collect::<Vec<_>>(), [ErrorKind::CheckHyphens]);
+
+    let errors = config
+        .collect_all_errors(true)
+        .to_unicode(&domain)
+        .1
+        .unwrap_err();
+    assert_eq!(
+        errors.iter().collect::<Vec<_>>(),
+        [ErrorKind::CheckHyphens, ErrorKind::StartCombiningMark]
+    );
+    assert_eq!(errors.failed_label(), Some((0, "-\u{301}".to_owned())));
+}
+
+#[test]
+fn std3_allowed() {
+    let config = idna::Config::default().use_std3_ascii_rules(true);
+    assert!(config.to_ascii("_sip._tcp.example.com").is_err());
+
+    let config = config.std3_allowed(|c| c == '_');
+    assert_eq!(
+        config.to_ascii("_sip._tcp.example.com").unwrap(),
+        "_sip._tcp.example.com"
+    );
+    assert!(config.to_ascii("_sip.a+b.example.com").is_err());
+
+    let config = idna::Config::default().std3_allowed(|_| false);
+    assert_eq!(config.to_ascii("a+b").unwrap(), "a+b");
+}
+
+#[test]
+fn ignore_invalid_punycode() {
+    let config = idna::Config::default();
+    assert!(config.to_ascii("xn--a-99999999999.com").is_err());
+    assert!(config.to_unicode("xn--a-99999999999.com").1.is_err());
+
+    let config = config.ignore_invalid_punycode(true);
+    assert_eq!(
+        config.to_ascii("XN--a-99999999999.com").unwrap(),
+        "xn--a-99999999999.com"
+    );
+    let (unicode, result) = config.to_unicode("xn--a-99999999999.com");
+    assert_eq!(unicode, "xn--a-99999999999.com");
+    assert!(result.is_ok());
+    assert_eq!(config.to_ascii("xn--bcher-kva").unwrap(), "xn--bcher-kva");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn errors_serde() {
+    let config = idna::Config::default().verify_dns_length(true);
+    let errors = config.to_ascii("xn--a-99999999999..com").unwrap_err();
+    let json = serde_json::to_string(&errors).unwrap();
+    assert_eq!(json, r#"["punycode","too_short_for_dns"]"#);
+
+    let errors: idna::Errors = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        format!("{:?}", errors),
+        "Errors { punycode, too_short_for_dns }"
+    );
+    assert!(serde_json::from_str::<idna::Errors>(r#"["bogus"]"#).is_err());
+}
+
+#[test]
+fn max_lengths() {
+    let config = idna::Config::default().verify_dns_length(true);
+    let long_label = "a".repeat(100);
+    assert!(config.to_ascii(&long_label).is_err());
+    assert!(config.to_ascii(&"a.".repeat(200)).is_err());
+    assert!(config.to_ascii(&"ü".repeat(70)).is_err());
+
+    let config = config.max_label_length(255).max_domain_length(1000);
+    assert_eq!(config.to_ascii(&long_label).unwrap(), long_label);
+    assert!(config.to_ascii(&"a.".repeat(200)).is_ok());
+    assert!(config.to_ascii(&"ü".repeat(70)).is_ok());
+    assert!(config.to_ascii("a..b").is_err());
+
+    let config = config.max_label_length(3).max_domain_length(7);
+    assert!(config.to_ascii("abc.def").is_ok());
+    assert!(config.to_ascii("abcd.ef").is_err());
+    assert!(config.to_ascii("abc.def.g").is_err());
+    assert!(config.label_to_ascii("abc").is_ok());
+    assert!(config.label_to_ascii("abcd").is_err());
+}
+
+#[test]
+fn to_ascii_batch() {
+    let config = idna::Config::default().verify_dns_length(true);
+    let domains = ["Bücher.de", "example.com", "", "xn--a.b", "☕.us", "a..b"];
+    let results = config
+        .to_ascii_batch(domains.iter().cloned())
+        .collect::<Vec<_>>();
+    assert_eq!(results.len(), domains.len());
+    for (domain, result) in domains.iter().zip(results) {
+        assert_eq!(result.ok(), config.to_ascii(domain).ok());
+    }
+}
+
+#[test]
+fn codec_to_ascii_into_buffer() {
+    let mut codec = idna::Idna::with_capacity(idna::Config::default().verify_dns_length(true), 64);
+    let mut out = String::from("stale.example");
+
+    assert_matches!(codec.to_ascii("Bücher.de", &mut out), Ok(()));
+    assert_eq!(out, "xn--bcher-kva.de");
+
+    assert_matches!(codec.to_ascii("example.com", &mut out), Ok(()));
+    assert_eq!(out, "example.com");
+
+    assert!(codec.to_ascii("a..b", &mut out).is_err());
+    assert_matches!(codec.to_ascii("☕.us", &mut out), Ok(()));
+    assert_eq!(out, "xn--53h.us");
+}
+
+#[test]
+fn restrict_mixed_script() {
+    let config = idna::Config::default().restrict_mixed_script(true);
+    for domain in &[
+        "example.com",
+        "пример.рф",
+        "παράδειγμα.gr",
+        "例え.jp",
+        "日本語テキスト.jp",
+        "abc日本語.jp",
+        "한국어漢字.kr",
+        "abcქართული.com",
+        "abc-123.com",
+        "аррӏе.com",
+        "xn--80ak6aa92e.com",
+    ] {
+        assert!(config.to_ascii(domain).is_ok(), "{}", domain);
+    }
+
+    // Cyrillic "а" with Latin, or Latin or Cyrillic with Greek and Han
+    for domain in &["аpple.com", "pаypal.com", "abcαβγ.com", "аб日本.jp"] {
+        let errors = config.to_ascii(domain).unwrap_err();
+        assert_eq!(
+            errors.iter().collect::<Vec<_>>(),
+            [idna::ErrorKind::MixedScript],
+            "{}",
+            domain
+        );
+    }
+    assert!(idna::Config::default().to_ascii("pаypal.com").is_ok());
+}
+
+#[test]
+fn unicode_version() {
+    assert_eq!(idna::unicode_version(), idna::UNICODE_VERSION);
+    assert_eq!(idna::UNICODE_VERSION, (13, 0, 0));
+}
+
+#[test]
+fn map_status() {
+    use idna::MappingStatus;
+    assert_eq!(idna::map_status('a'), MappingStatus::Valid);
+    assert_eq!(idna::map_status('A'), MappingStatus::Mapped("a"));
+    assert_eq!(idna::map_status('\u{ad}'), MappingStatus::Ignored);
+    assert_eq!(idna::map_status('ß'), MappingStatus::Deviation("ss"));
+    assert_eq!(idna::map_status('\u{fffe}'), MappingStatus::Disallowed);
+    assert_eq!(idna::map_status('_'), MappingStatus::DisallowedStd3Valid);
+    assert_eq!(
+        idna::map_status('\u{2474}'),
+        MappingStatus::DisallowedStd3Mapped("(1)")
+    );
+    assert_eq!(idna::map_status('\u{2122}'), MappingStatus::Mapped("tm"));
+}
+
+#[test]
+fn deviation_mapping() {
+    assert_eq!(idna::deviation_mapping('ß'), Some("ss"));
+    assert_eq!(idna::deviation_mapping('ς'), Some("σ"));
+    assert_eq!(idna::deviation_mapping('\u{200C}'), Some(""));
+    assert_eq!(idna::deviation_mapping('\u{200D}'), Some(""));
+    assert_eq!(idna::deviation_mapping('a'), None);
+    assert_eq!(idna::deviation_mapping('A'), None);
+
+    let domain = "faß.de";
+    assert!(domain.chars().any(|c| idna::deviation_mapping(c).is_some()));
+    assert_ne!(
+        idna::Config::transitional().to_ascii(domain).unwrap(),
+        idna::Config::nontransitional().to_ascii(domain).unwrap()
+    );
+}
+
+#[test]
+fn check_context_o() {
+    // Labels of Arabic-Indic digits alone break the bidi rules
+    let config = idna::Config::default()
+        .check_bidi(false)
+        .check_context_o(true);
+    let valid = [
+        "l\u{b7}l",
+        "\u{375}\u{3b1}",
+        "\u{5d0}\u{5f3}",
+        "\u{5d0}\u{5f4}\u{5d1}",
+        "\u{30a2}\u{30fb}\u{30a4}",
+        "\u{3042}\u{30fb}",
+        "\u{5b57}\u{30fb}",
+        "\u{660}\u{661}",
+        "\u{6f0}\u{6f1}",
+        "\u{660}.\u{6f0}",
+    ];
+    for label in &valid {
+        assert!(config.to_ascii(label).is_ok(), "{:?}", label);
+    }
+
+    let invalid = [
+        "\u{b7}l",
+        "l\u{b7}",
+        "a\u{b7}l",
+        "\u{375}a",
+        "\u{375}",
+        "a\u{5f3}",
+        "\u{5f4}\u{5d0}",
+        "a\u{30fb}",
+        "\u{30fb}",
+        "\u{660}\u{6f0}",
+    ];
+    for label in &invalid {
+        let errors = config.to_ascii(label).unwrap_err();
+        assert_eq!(
+            errors.iter().collect::<Vec<_>>(),
+            [idna::ErrorKind::CheckContextO],
+            "{:?}",
+            label
+        );
+        let config = idna::Config::default().check_bidi(false);
+        assert!(config.to_ascii(label).is_ok(), "{:?}", label);
+    }
+}
+
+#[test]
+fn processing_profiles() {
+    let nontransitional = idna::Config::default()
+        .use_std3_ascii_rules(true)
+        .verify_dns_length(true)
+        .check_hyphens(true);
+    let transitional = nontransitional.transitional_processing(true);
+    for domain in &[
+        "faß.de",
+        "βόλος.gr",
+        "a_b.com",
+        "-a.com",
+        "a..com",
+        "Example.COM",
+    ] {
+        assert_eq!(
+            idna::Config::nontransitional().to_ascii(domain).ok(),
+            nontransitional.to_ascii(domain).ok()
+        );
+        assert_eq!(
+            idna::Config::transitional().to_ascii(domain).ok(),
+            transitional.to_ascii(domain).ok()
+        );
+    }
+    assert_eq!(
+        idna::Config::transitional().to_ascii("faß.de").unwrap(),
+        "fass.de"
+    );
+    assert_eq!(
+        idna::Config::nontransitional().to_ascii("faß.de").unwrap(),
+        "xn--fa-hia.de"
+    );
+    assert!(idna::Config::nontransitional().to_ascii("a_b.com").is_err());
+}
+
+#[test]
+fn verify_dns_length_trailing_dot() {
+    let config = idna::Config::default().verify_dns_length(true);
+    assert_eq!(config.to_ascii("example.com.").unwrap(), "example.com.");
+    assert_eq!(config.to_ascii("bücher.de.").unwrap(), "xn--bcher-kva.de.");
+    for domain in &["example.com..", "example..com.", ".example.com.", ".", ""] {
+        let errors = config.to_ascii(domain).unwrap_err();
+        assert_eq!(
+            errors.iter().collect::<Vec<_>>(),
+            [idna::ErrorKind::TooShortForDns],
+            "{:?}",
+            domain
+        );
+    }
+    let domain = "a.".repeat(126) + "a.";
+    assert_eq!(domain.len(), 254);
+    assert!(config.to_ascii(&domain).is_ok());
+}
+
+#[test]
+fn to_ascii_uppercase() {
+    let config = idna::Config::default();
+    assert_eq!(
+        config.to_ascii("WWW.Example.COM").unwrap(),
+        "www.example.com"
+    );
+    assert_eq!(config.to_ascii("A1.B2").unwrap(), "a1.b2");
+    assert_eq!(
+        config.to_ascii("XN--BCHER-KVA.de").unwrap(),
+        "xn--bcher-kva.de"
+    );
+    assert_eq!(
+        config.to_ascii("Xn--Bcher-kva.de").unwrap(),
+        "xn--bcher-kva.de"
+    );
+    assert!(config.to_ascii("XN--A-99999999999.de").is_err());
+
+    let config = config.check_hyphens(true);
+    for domain in &["-A.com", "A-.com", "Example.COM-"] {
+        assert!(config.to_ascii(domain).is_err(), "{}", domain);
+    }
+
+    let mut codec = idna::Idna::new(idna::Config::default());
+    let mut out = String::new();
+    codec.to_ascii("EXAMPLE.com", &mut out).unwrap();
+    assert_eq!(out, "example.com");
+}
+
+#[test]
+fn is_bidi_domain() {
+    assert!(!idna::is_bidi_domain("example.com"));
+    assert!(!idna::is_bidi_domain("bücher.de"));
+    assert!(!idna::is_bidi_domain(""));
+    assert!(idna::is_bidi_domain("example.\u{5d0}"));
+    assert!(idna::is_bidi_domain(
+        "\u{627}\u{644}\u{639}\u{631}\u{628}\u{64a}\u{629}"
+    ));
+    assert!(idna::is_bidi_domain("a\u{660}"));
+    // Punycode labels are not decoded
+    assert!(!idna::is_bidi_domain("xn--4gbrim"));
+}
+
+#[test]
+fn check_hyphens_positional() {
+    let config = idna::Config::default().check_hyphens_positional(true);
+    for domain in &["ab--cd.com", "éb--cd.com", "example.ab--", "xn--ab--cd-gva"] {
+        let errors = config.to_ascii(domain).unwrap_err();
+        assert_eq!(
+            errors.iter().collect::<Vec<_>>(),
+            [idna::ErrorKind::CheckHyphens],
+            "{}",
+            domain
+        );
+        assert!(
+            idna::Config::default().to_ascii(domain).is_ok(),
+            "{}",
+            domain
+        );
+    }
+    for domain in &[
+        "a--b.com",
+        "abc--d.com",
+        "xn--bcher-kva.de",
+        "bücher.de",
+        "ab-cd",
+    ] {
+        assert!(config.to_ascii(domain).is_ok(), "{}", domain);
+    }
+}
+
+#[test]
+fn to_ascii_and_unicode() {
+    let config = idna::Config::default();
+    for domain in &[
+        "Bücher.DE",
+        "xn--bcher-kva.de",
+        "example.com",
+        "EXAMPLE.com",
+        "faß.de",
+        "a\u{200D}.com",
+        "xn--a-99999999999.com",
+    ] {
+        let (ascii, unicode) = config.to_ascii_and_unicode(domain);
+        assert_eq!(ascii.ok(), config.to_ascii(domain).ok(), "{}", domain);
+        assert_eq!(unicode, config.to_unicode_mapped(domain).0, "{}", domain);
+    }
+    let (ascii, unicode) = config.to_ascii_and_unicode("Bücher.DE");
+    assert_eq!(ascii.unwrap(), "xn--bcher-kva.de");
+    assert_eq!(unicode, "bücher.de");
+}
+
+#[test]
+fn map_chars() {
+    let map =
+        |input: &str, config: idna::Config| idna::map_chars(input, config).collect::<String>();
+    assert_eq!(
+        map("Ab\u{AD}C.\u{2474}", idna::Config::default()),
+        "abc.(1)"
+    );
+    assert_eq!(map("faß", idna::Config::default()), "faß");
+    assert_eq!(map("faß", idna::Config::transitional()), "fass");
+    // Not normalized: a combining mark is yielded as is.
+    assert_eq!(map("e\u{301}", idna::Config::default()), "e\u{301}");
+    // Not validated: disallowed characters pass through.
+    assert_eq!(map("a\u{fffe}", idna::Config::default()), "a\u{fffe}");
+}
+
+#[test]
+fn assume_nfc() {
+    let config = idna::Config::default().assume_nfc(true);
+    assert_eq!(
+        config.to_ascii("Bücher.example").unwrap(),
+        "xn--bcher-kva.example"
+    );
+    assert_eq!(config.to_ascii("\u{e9}").unwrap(), "xn--9ca");
+    // Wrong, but that's the documented misuse.
+    assert_eq!(config.to_ascii("e\u{301}").unwrap(), "xn--e-xbb");
+    assert_eq!(
+        idna::Config::default().to_ascii("e\u{301}").unwrap(),
+        "xn--9ca"
+    );
+    // Decoded labels are still checked.
+    assert!(config.to_ascii("xn--e-xbb").is_err());
+}
+
+#[test]
+fn to_ascii_assume_mapped() {
+    let config = idna::Config::default();
+    for domain in &[
+        "example.com",
+        "bücher.example",
+        "ه.example",
+        "a.b.",
+        "ab--cd",
+    ] {
+        let (mapped, result) = config.to_unicode(domain);
+        result.unwrap();
+        assert_eq!(
+            config.to_ascii_assume_mapped(&mapped).unwrap(),
+            config.to_ascii(domain).unwrap()
+        );
+    }
+    // No validity checks.
+    assert_eq!(
+        config.to_ascii_assume_mapped("a\u{fffe}").unwrap(),
+        "xn--a-s10i"
+    );
+    assert_eq!(config.to_ascii_assume_mapped("xn--").unwrap(), "xn--");
+
+    let config = config.verify_dns_length(true);
+    let long = "ü".repeat(60);
+    let errors = config.to_ascii_assume_mapped(&long).unwrap_err();
+    assert!(errors
+        .iter()
+        .any(|kind| kind == idna::ErrorKind::TooLongForDns));
+    assert!(config.to_ascii_assume_mapped("a..b").is_err());
+}
+
+#[test]
+fn had_deviations() {
+    for &transitional in &[false, true] {
+        let mut codec =
+            idna::Idna::new(idna::Config::default().transitional_processing(transitional));
+        let mut out = String::new();
+        codec.to_ascii("faß.de", &mut out).unwrap();
+        assert!(codec.had_deviations());
+        codec.to_ascii("fass.de", &mut out).unwrap();
+        assert!(!codec.had_deviations());
+        let _ = codec.to_unicode("a\u{200D}.de", &mut out);
+        assert!(codec.had_deviations());
+        codec.to_unicode("xn--fa-hia.de", &mut out).unwrap();
+        assert!(!codec.had_deviations());
+    }
+
+    let errors = idna::Config::default().to_ascii("ς\u{fffe}").unwrap_err();
+    assert!(errors.had_deviations());
+    assert_eq!(
+        format!("{:?}", errors),
+        "Errors { invalid_mapping, disallowed_character }"
+    );
+    let errors = idna::Config::default().to_ascii("a\u{fffe}").unwrap_err();
+    assert!(!errors.had_deviations());
+}
+
+#[test]
+fn max_label_code_points() {
+    let config = idna::Config::default().max_label_code_points(6);
+    for domain in &[
+        "abcdef.com",
+        "ABCDEF.com",
+        "bücher.de",
+        "xn--bcher-kva.de",
+        "abcde\u{301}f.de",
+    ] {
+        assert!(config.to_ascii(domain).is_ok(), "{}", domain);
+    }
+    for domain in &[
+        "abcdefg.com",
+        "ABCDEFG.com",
+        "büchers.de",
+        "xn--bchers-3ya.de",
+        "a.例えばテストだ",
+    ] {
+        let errors = config.to_ascii(domain).unwrap_err();
+        assert_eq!(
+            errors.iter().collect::<Vec<_>>(),
+            [idna::ErrorKind::TooManyCodePoints],
+            "{}",
+            domain
+        );
+        assert!(
+            idna::Config::default().to_ascii(domain).is_ok(),
+            "{}",
+            domain
+        );
+        assert!(config.to_unicode(domain).1.is_err(), "{}", domain);
+    }
+
+    // `to_ascii_cow` borrows simple domains, but checks the limit too.
+    let config = idna::Config::default().max_label_code_points(3);
+    let kinds = |errors: idna::Errors| errors.iter().collect::<Vec<_>>();
+    for domain in &["abcd.com", "abc.com", "ab.abcd", "ABCD.com"] {
+        assert_eq!(
+            config
+                .to_ascii_cow(domain)
+                .map(|ascii| ascii.into_owned())
+                .map_err(kinds),
+            config.to_ascii(domain).map_err(kinds),
+            "{}",
+            domain
+        );
+    }
+    assert!(config.to_ascii_cow("abcd.com").is_err());
+}
+
+#[test]
+fn whatwg_url_host() {
+    let config = idna::Config::whatwg_url_host();
+    let long_label = "a".repeat(64);
+    for domain in &[
+        "faß.de",
+        "a_b.com",
+        "-a-.com",
+        "ab--cd.com",
+        "a..com",
+        "Example.COM",
+        &*long_label,
+        "a\u{200D}b.com",
+        "xn--invalid-.com",
+        "\u{5d0}a.com",
+    ] {
+        assert_eq!(
+            config.to_ascii(domain).ok(),
+            idna::domain_to_ascii(domain).ok(),
+            "{}",
+            domain
+        );
+        assert_eq!(
+            config.to_ascii(domain).ok(),
+            idna::Config::default().to_ascii(domain).ok(),
+            "{}",
+            domain
+        );
+    }
+    assert_eq!(config.to_ascii("faß.de").unwrap(), "xn--fa-hia.de");
+    assert_eq!(config.to_ascii("a_b.-c-").unwrap(), "a_b.-c-");
+    assert_eq!(config.to_ascii(&long_label).unwrap(), long_label);
+    assert!(config.to_ascii("a\u{200D}b.com").is_err());
+    assert!(config.to_ascii("\u{5d0}a.com").is_err());
+}
+
+#[test]
+fn percent_decode_input() {
+    let config = idna::Config::default().percent_decode_input(true);
+    assert_eq!(config.to_ascii("caf%C3%A9.com").unwrap(), "xn--caf-dma.com");
+    assert_eq!(config.to_ascii("caf%c3%a9.com").unwrap(), "xn--caf-dma.com");
+    assert_eq!(config.to_ascii("%45xample.com").unwrap(), "example.com");
+    assert_eq!(config.to_unicode("caf%C3%A9.com").0, "café.com");
+    assert_eq!(config.to_ascii("example.com").unwrap(), "example.com");
+    // Without the option, `%` is kept.
+    assert_eq!(
+        idna::Config::default().to_ascii("caf%C3%A9.com").unwrap(),
+        "caf%c3%a9.com"
+    );
+
+    for domain in &["a%.com", "a%4.com", "a%zz.com", "a%C3.com", "a%FF.com"] {
+        let errors = config.to_ascii(domain).unwrap_err();
+        assert_eq!(
+            errors.iter().collect::<Vec<_>>(),
+            [idna::ErrorKind::PercentDecode],
+            "{}",
+            domain
+        );
+    }
+    let (unicode, result) = config.to_unicode("A%zz.com");
+    assert_eq!(unicode, "a%zz.com");
+    assert!(result.is_err());
+}
+
+#[test]
+fn validate() {
+    let configs = [
+        idna::Config::default(),
+        idna::Config::default().use_std3_ascii_rules(true),
+        idna::Config::default().verify_dns_length(true),
+        idna::Config::default().transitional_processing(true),
+        idna::Config::whatwg_url_host(),
+    ];
+    let long_label = "a".repeat(64);
+    let long_domain = format!("{}.example", long_label);
+    // Too long for the Punycode encoder, which overflows.
+    let overflow = format!("{}\u{20000}.example", "a".repeat(70_000));
+    let domains = [
+        "example.com",
+        "bücher.example",
+        "Bücher.EXAMPLE",
+        "xn--bcher-kva.example",
+        "xn--a.example",
+        "a_b.example",
+        "-a.example",
+        "a..example",
+        "\u{200D}.example",
+        "\u{5d0}1.example",
+        "",
+        &long_domain,
+        &overflow,
+    ];
+    for config in &configs {
+        for domain in &domains {
+            let kinds = |errors: idna::Errors| errors.iter().collect::<Vec<_>>();
+            let expected = config.to_ascii(domain).map(|_| ()).map_err(kinds);
+            assert_eq!(
+                config.validate(domain).map_err(kinds),
+                expected,
+                "{:?}",
+                domain
+            );
+        }
+    }
+
+    let errors = idna::Config::default().validate(&overflow).unwrap_err();
+    assert_eq!(
+        errors.iter().collect::<Vec<_>>(),
+        [idna::ErrorKind::Punycode]
+    );
+
+    let mut codec = idna::Idna::new(idna::Config::default().transitional_processing(true));
+    assert!(codec.validate("straße.de").is_ok());
+    assert!(codec.had_deviations());
+}
@@ -168,6 +168,41 @@ pub const NON_ALPHANUMERIC: &AsciiSet = &CONTROLS
     .add(b'}')
     .add(b'~');
 
+/// The set the `url` crate uses to percent-encode the username and password of URLs.
+///
+/// This is the path percent-encode set, plus `/`, `:`, `;`, `=`, `@`, `[` to `^`, and `|`.
+/// Encoding credentials with it gives what the URL parser and setters would produce.
+///
+/// ```
+/// use percent_encoding::{utf8_percent_encode, USERINFO};
+///
+/// assert_eq!(utf8_percent_encode("p@ss:w/rd", USERINFO).to_string(), "p%40ss%3Aw%2Frd");
+/// ```
+///
+/// <https://url.spec.whatwg.org/#userinfo-percent-encode-set>
+pub const USERINFO: &AsciiSet = &CONTROLS
+    // The fragment percent-encode set
+    .add(b' ')
+    .add(b'"')
+    .add(b'<')
+    .add(b'>')
+    .add(b'`')
+    // The path percent-encode set
+    .add(b'#')
+    .add(b'?')
+    .add(b'{')
+    .add(b'}')
+    .add(b'/')
+    .add(b':')
+    .add(b';')
+    .add(b'=')
+    .add(b'@')
+    .add(b'[')
+    .add(b'\\')
+    .add(b']')
+    .add(b'^')
+    .add(b'|');
+
 /// Return the percent-encoding of the given byte.
 ///
 /// This is unconditional, unlike `percent_encode()` which has an `AsciiSet` parameter.
@@ -438,6 +473,41 @@ impl<'a> PercentDecode<'a> {
     }
 }
 
+/// Decode bytes as UTF-8, lossily, appending the result to `output`.
+///
+/// Invalid UTF-8 byte sequences are replaced with � U+FFFD, the replacement character,
+/// like in `String::from_utf8_lossy`. Nothing is allocated beyond growing `output`,
+/// which makes this suitable for decoding into a reused buffer.
+///
+/// ```
+/// use percent_encoding::{decode_utf8_lossy_into, percent_decode_str};
+///
+/// let mut bytes = Vec::new();
+/// let mut output = String::from("name=");
+/// bytes.extend(percent_decode_str("caf%C3%A9%FF"));
+/// decode_utf8_lossy_into(&bytes, &mut output);
+/// assert_eq!(output, "name=café\u{FFFD}");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_utf8_lossy_into(input: &[u8], output: &mut String) {
+    let mut input = input;
+    loop {
+        match str::from_utf8(input) {
+            Ok(utf8) => return output.push_str(utf8),
+            Err(error) => {
+                let (valid, rest) = input.split_at(error.valid_up_to());
+                // `valid_up_to` is the length of the longest valid UTF-8 prefix
+                output.push_str(unsafe { str::from_utf8_unchecked(valid) });
+                output.push('\u{FFFD}');
+                match error.error_len() {
+                    Some(len) => input = &rest[len..],
+                    None => return,
+                }
+            }
+        }
+    }
+}
+
 #[cfg(feature = "alloc")]
 fn decode_utf8_lossy(input: Cow<'_, [u8]>) -> Cow<'_, str> {
     // Note: This function is duplicated in `form_urlencoded/src/query_encoding.rs`.
@@ -12,5 +12,20 @@ fn short(bench: &mut Bencher) {
     bench.iter(|| black_box(url).parse::<Url>().unwrap());
 }
 
-benchmark_group!(benches, short);
+fn set_fragment(bench: &mut Bencher) {
+    let mut url = Url::parse("https://example.com/bench#top").unwrap();
+
+    bench.iter(|| url.set_fragment(black_box(Some("section-2"))));
+}
+
+fn take_and_push_fragment(bench: &mut Bencher) {
+    let mut url = Url::parse("https://example.com/bench#top").unwrap();
+
+    bench.iter(|| {
+        url.take_fragment();
+        url.push_fragment(black_box("section-2"))
+    });
+}
+
+benchmark_group!(benches, short, set_fragment, take_and_push_fragment);
 benchmark_main!(benches);
@@ -59,6 +59,21 @@ pub enum Host<S = String> {
     Ipv6(Ipv6Addr),
 }
 
+/// The kind of host of an URL, see `Url::host_kind`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum HostKind {
+    /// A domain name of a special URL, which went through IDNA.
+    Domain,
+    /// An IPv4 address, only in special URLs.
+    Ipv4,
+    /// An IPv6 address.
+    Ipv6,
+    /// An empty host, like in `file:///tmp/foo`.
+    Empty,
+    /// The opaque host of a non-special URL, which is percent-encoded rather than going through IDNA.
+    Opaque,
+}
+
 impl<'a> Host<&'a str> {
     /// Return a copy of `self` that owns an allocated `String` but does not borrow an `&Url`.
     pub fn to_owned(&self) -> Host<String> {
@@ -70,6 +85,28 @@ impl<'a> Host<&'a str> {
     }
 }
 
+impl<S: AsRef<str>> Host<S> {
+    /// Return a `Host<&str>` that borrows the string of `self`, without allocating.
+    ///
+    /// This is the converse of `Host::<&str>::to_owned`.
+    ///
+    /// ```rust
+    /// use url::{Host, Url};
+    ///
+    /// let url = Url::parse("https://example.net/").unwrap();
+    /// let owned: Host<String> = url.host().unwrap().to_owned();
+    /// assert_eq!(owned.as_ref(), Host::Domain("example.net"));
+    /// assert_eq!(owned.as_ref(), url.host().unwrap());
+    /// ```
+    pub fn as_ref(&self) -> Host<&str> {
+        match *self {
+            Host::Domain(ref domain) => Host::Domain(domain.as_ref()),
+            Host::Ipv4(address) => Host::Ipv4(address),
+            Host::Ipv6(address) => Host::Ipv6(address),
+        }
+    }
+}
+
 impl Host<String> {
     /// Parse a host: either an IPv6 address in [] square brackets, or a domain.
     ///
@@ -118,7 +155,28 @@ impl Host<String> {
         }
     }
 
-    // <https://url.spec.whatwg.org/#concept-opaque-host-parser>
+    /// Parse the host of a URL with a non-special scheme, also called an opaque host:
+    /// either an IPv6 address in [] square brackets, or a percent-encoded domain.
+    ///
+    /// Unlike `Host::parse`, IPv4 addresses are not recognized and domains don't go
+    /// through IDNA: non-ASCII characters and C0 controls are percent-encoded,
+    /// and forbidden host code points like `/`, `:` or `@` are rejected.
+    ///
+    /// ```
+    /// use url::Host;
+    ///
+    /// assert_eq!(
+    ///     Host::parse_opaque("Bücher.de").unwrap(),
+    ///     Host::Domain("B%C3%BCcher.de".to_owned())
+    /// );
+    /// assert_eq!(
+    ///     Host::parse_opaque("127.0.0.1").unwrap(),
+    ///     Host::Domain("127.0.0.1".to_owned())
+    /// );
+    /// assert!(Host::parse_opaque("a:b").is_err());
+    /// ```
+    ///
+    /// <https://url.spec.whatwg.org/#concept-opaque-host-parser>
     pub fn parse_opaque(input: &str) -> Result<Self, ParseError> {
         if input.starts_with('[') {
             if !input.ends_with(']') {
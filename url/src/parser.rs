@@ -13,7 +13,7 @@ use std::str;
 use crate::host::{Host, HostInternal};
 use crate::Url;
 use form_urlencoded::EncodingOverride;
-use percent_encoding::{percent_encode, utf8_percent_encode, AsciiSet, CONTROLS};
+use percent_encoding::{percent_encode, utf8_percent_encode, AsciiSet, CONTROLS, USERINFO};
 
 /// https://url.spec.whatwg.org/#fragment-percent-encode-set
 const FRAGMENT: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'<').add(b'>').add(b'`');
@@ -21,19 +21,6 @@ const FRAGMENT: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'<').add(b'>').ad
 /// https://url.spec.whatwg.org/#path-percent-encode-set
 const PATH: &AsciiSet = &FRAGMENT.add(b'#').add(b'?').add(b'{').add(b'}');
 
-/// https://url.spec.whatwg.org/#userinfo-percent-encode-set
-pub(crate) const USERINFO: &AsciiSet = &PATH
-    .add(b'/')
-    .add(b':')
-    .add(b';')
-    .add(b'=')
-    .add(b'@')
-    .add(b'[')
-    .add(b'\\')
-    .add(b']')
-    .add(b'^')
-    .add(b'|');
-
 pub(crate) const PATH_SEGMENT: &AsciiSet = &PATH.add(b'/').add(b'%');
 
 // The backslash (\) character is treated as a path separator in special URLs
@@ -91,6 +78,100 @@ simple_enum_error! {
     RelativeUrlWithCannotBeABaseBase => "relative URL with a cannot-be-a-base base",
     SetHostOnCannotBeABaseUrl => "a cannot-be-a-base URL doesn’t have a host to set",
     Overflow => "URLs more than 4 GB are not supported",
+    IgnoredCharacter => "tab, newline, or leading or trailing C0 control or space",
+}
+
+impl ParseError {
+    /// Return the category of this error, which is less likely than the error itself
+    /// to change when new errors are introduced.
+    ///
+    /// ```
+    /// use url::{ParseErrorKind, Url};
+    ///
+    /// let error = Url::parse("http://[::1").unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::Host);
+    /// ```
+    pub fn kind(&self) -> ParseErrorKind {
+        match *self {
+            ParseError::EmptyHost
+            | ParseError::InvalidIpv4Address
+            | ParseError::InvalidIpv6Address
+            | ParseError::InvalidDomainCharacter => ParseErrorKind::Host,
+            ParseError::IdnaError => ParseErrorKind::Idna,
+            ParseError::InvalidPort => ParseErrorKind::Port,
+            ParseError::RelativeUrlWithoutBase | ParseError::RelativeUrlWithCannotBeABaseBase => {
+                ParseErrorKind::RelativeWithoutBase
+            }
+            ParseError::SetHostOnCannotBeABaseUrl => ParseErrorKind::Setter,
+            ParseError::Overflow => ParseErrorKind::TooLong,
+            ParseError::IgnoredCharacter => ParseErrorKind::Syntax,
+            ParseError::__FutureProof => {
+                unreachable!("Don't abuse the FutureProof!");
+            }
+        }
+    }
+}
+
+/// The category of a `ParseError`, see `ParseError::kind`.
+///
+/// This may be extended in the future so exhaustive matching is
+/// discouraged with an unused variant.
+#[allow(clippy::manual_non_exhaustive)] // introduced in 1.40, MSRV is 1.36
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub enum ParseErrorKind {
+    /// The input is rejected by a strict parser, see `ParseOptions::strict`.
+    Syntax,
+    /// The host is empty, an invalid IP address, or has a forbidden code point.
+    Host,
+    /// The domain is not valid per IDNA.
+    Idna,
+    /// The port is not a number that fits in 16 bits.
+    Port,
+    /// The input is a relative URL without a base URL that can be used to resolve it.
+    RelativeWithoutBase,
+    /// A setter was called on a URL that doesn't have the component to set.
+    Setter,
+    /// The URL would be too long.
+    TooLong,
+    /// Unused variant enable non-exhaustive matching
+    #[doc(hidden)]
+    __FutureProof,
+}
+
+/// Errors of `Url::parse_with_base_str`, telling which of the two strings is invalid.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ParseWithBaseError {
+    /// The base URL could not be parsed.
+    Base(ParseError),
+    /// The input could not be parsed against the base URL.
+    Input(ParseError),
+}
+
+impl fmt::Display for ParseWithBaseError {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            ParseWithBaseError::Base(error) => write!(fmt, "invalid base URL: {}", error),
+            ParseWithBaseError::Input(error) => fmt::Display::fmt(&error, fmt),
+        }
+    }
+}
+
+impl Error for ParseWithBaseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ParseWithBaseError::Base(ref error) | ParseWithBaseError::Input(ref error) => {
+                Some(error)
+            }
+        }
+    }
+}
+
+impl From<ParseWithBaseError> for ParseError {
+    fn from(error: ParseWithBaseError) -> ParseError {
+        match error {
+            ParseWithBaseError::Base(error) | ParseWithBaseError::Input(error) => error,
+        }
+    }
 }
 
 impl From<::idna::Errors> for ParseError {
@@ -1532,6 +1613,32 @@ fn is_url_code_point(c: char) -> bool {
         '\u{F0000}'..='\u{FFFFD}' | '\u{100000}'..='\u{10FFFD}')
 }
 
+/// Return the byte position of the first character that the URL parser would ignore:
+/// a leading or trailing C0 control or space, or a tab or newline elsewhere.
+///
+/// These are the characters that make a strict parser, see `ParseOptions::strict`,
+/// fail with `ParseError::IgnoredCharacter`.
+///
+/// ```
+/// use url::{find_ignored_character, ParseError, Url};
+///
+/// let input = "https://exa\tmple.com/";
+/// assert_eq!(Url::options().strict(true).parse(input), Err(ParseError::IgnoredCharacter));
+/// assert_eq!(find_ignored_character(input), Some(11));
+/// assert_eq!(find_ignored_character("https://example.com/"), None);
+/// ```
+pub fn find_ignored_character(input: &str) -> Option<usize> {
+    if input.starts_with(c0_control_or_space) {
+        return Some(0);
+    }
+    let end = input.trim_end_matches(c0_control_or_space).len();
+    match input[..end].find(ascii_tab_or_new_line) {
+        Some(position) => Some(position),
+        None if end < input.len() => Some(end),
+        None => None,
+    }
+}
+
 /// https://url.spec.whatwg.org/#c0-controls-and-space
 #[inline]
 fn c0_control_or_space(ch: char) -> bool {
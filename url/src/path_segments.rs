@@ -94,6 +94,36 @@ impl<'a> PathSegmentsMut<'a> {
         self
     }
 
+    /// Replace all segments in the path with the given ones.
+    ///
+    /// This is `.clear()` followed by `.extend(segments)`: each segment is percent-encoded,
+    /// including any `/`, `?`, `#`, or `%` character it contains.
+    ///
+    /// Returns `&mut Self` so that method calls can be chained.
+    ///
+    /// Example:
+    ///
+    /// ```rust
+    /// use url::Url;
+    /// # use std::error::Error;
+    ///
+    /// # fn run() -> Result<(), Box<dyn Error>> {
+    /// let mut url = Url::parse("https://example.net/old/path?q#top")?;
+    /// url.path_segments_mut().map_err(|_| "cannot be base")?
+    ///     .replace_all(&["a/b", "c?d#e", "100%"]);
+    /// assert_eq!(url.as_str(), "https://example.net/a%2Fb/c%3Fd%23e/100%25?q#top");
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn replace_all<I>(&mut self, segments: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.clear().extend(segments)
+    }
+
     /// Remove the last segment of this URL’s path if it is empty,
     /// except if these was only one segment to begin with.
     ///
@@ -13,6 +13,7 @@
 
 use crate::parser::{default_port, Context, Input, Parser, SchemeType};
 use crate::{Host, ParseError, Position, Url};
+use std::borrow::Borrow;
 
 /// https://url.spec.whatwg.org/#dom-url-domaintoascii
 pub fn domain_to_ascii(domain: &str) -> String {
@@ -278,6 +279,26 @@ pub fn set_hash(url: &mut Url, new_hash: &str) {
     })
 }
 
+/// Getter for the name-value pairs of https://url.spec.whatwg.org/#dom-url-searchparams
+pub fn search_params(url: &Url) -> Vec<(String, String)> {
+    url.query_pairs().into_owned().collect()
+}
+
+/// Replace the query with the serialization of `pairs`, like
+/// https://url.spec.whatwg.org/#concept-urlsearchparams-update
+pub fn set_search_params<I, K, V>(url: &mut Url, pairs: I)
+where
+    I: IntoIterator,
+    I::Item: Borrow<(K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    let query = form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(pairs)
+        .finish();
+    url.set_query(if query.is_empty() { None } else { Some(&query) })
+}
+
 fn trim(s: &str) -> &str {
     if s.len() == 1 {
         ""